@@ -0,0 +1,175 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use directories::BaseDirs;
+
+use crate::help_system::runner::CommandRunner;
+
+/// A personal command cheatsheet, inspired by navi's cheatsheet repos: a
+/// `% tag` header line followed by `# description` / command pairs, with
+/// `<placeholder>` tokens marking the variable parts of a template.
+#[derive(Debug, Clone)]
+pub struct CheatSheet {
+    pub tag: String,
+    pub entries: Vec<CheatEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheatEntry {
+    pub description: String,
+    pub command: String,
+}
+
+/// Where `.cheat` files live: a user-owned config directory rather than
+/// `~/.cache`, since these are curated snippets the user writes and wants
+/// to keep, not a regenerable cache.
+pub fn cheats_dir() -> Result<PathBuf> {
+    let base = BaseDirs::new().context("failed to determine config directory")?;
+    Ok(base.config_dir().join("recaller").join("cheats"))
+}
+
+fn file_path(tag: &str) -> Result<PathBuf> {
+    Ok(cheats_dir()?.join(format!("{tag}.cheat")))
+}
+
+/// Parses a single `.cheat` file's contents into a `CheatSheet`.
+pub fn parse(raw: &str) -> CheatSheet {
+    let mut tag = String::new();
+    let mut entries = Vec::new();
+    let mut pending_desc: Option<String> = None;
+
+    for line in raw.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if let Some(rest) = trimmed.strip_prefix('%') {
+            tag = rest.trim().to_string();
+        } else if let Some(rest) = trimmed.strip_prefix('#') {
+            pending_desc = Some(rest.trim().to_string());
+        } else {
+            entries.push(CheatEntry {
+                description: pending_desc.take().unwrap_or_default(),
+                command: trimmed.to_string(),
+            });
+        }
+    }
+
+    CheatSheet { tag, entries }
+}
+
+/// Loads and parses `<tag>.cheat`, if it exists.
+pub fn load_sheet(tag: &str) -> Result<Option<CheatSheet>> {
+    let path = file_path(tag)?;
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    let raw = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read cheatsheet at {}", path.display()))?;
+    Ok(Some(parse(&raw)))
+}
+
+/// Loads every `.cheat` file in the cheats directory, sorted by tag.
+pub fn load_all() -> Result<Vec<CheatSheet>> {
+    let dir = cheats_dir()?;
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut sheets = Vec::new();
+    for entry in
+        fs::read_dir(&dir).with_context(|| format!("failed to read {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("cheat") {
+            continue;
+        }
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read cheatsheet at {}", path.display()))?;
+        sheets.push(parse(&raw));
+    }
+
+    sheets.sort_by(|a, b| a.tag.cmp(&b.tag));
+    Ok(sheets)
+}
+
+/// Appends a new entry to `<tag>.cheat`, creating the file (and its `%
+/// tag` header) if this is the first snippet saved under that tag.
+pub fn add_entry(tag: &str, description: &str, command: &str) -> Result<PathBuf> {
+    let dir = cheats_dir()?;
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+    let path = file_path(tag)?;
+    let mut contents = if path.is_file() {
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?
+    } else {
+        format!("% {tag}\n")
+    };
+
+    if !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push('\n');
+    if !description.is_empty() {
+        contents.push_str("# ");
+        contents.push_str(description);
+        contents.push('\n');
+    }
+    contents.push_str(command);
+    contents.push('\n');
+
+    fs::write(&path, contents).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// Returns `<tag>.cheat`'s path, creating it with just a `% tag` header if
+/// it doesn't exist yet, so `recaller cheats edit <tag>` always has
+/// something to open.
+pub fn ensure_file(tag: &str) -> Result<PathBuf> {
+    let dir = cheats_dir()?;
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+    let path = file_path(tag)?;
+    if !path.is_file() {
+        fs::write(&path, format!("% {tag}\n"))
+            .with_context(|| format!("failed to create {}", path.display()))?;
+    }
+    Ok(path)
+}
+
+/// Renders a `CheatSheet` into the plain-text form shown in the help pane.
+pub fn render(sheet: &CheatSheet) -> String {
+    let mut out = format!("📒 Cheats: {}\n\n", sheet.tag);
+    for entry in &sheet.entries {
+        if !entry.description.is_empty() {
+            out.push_str(&entry.description);
+            out.push('\n');
+        }
+        out.push_str("  ");
+        out.push_str(&entry.command);
+        out.push_str("\n\n");
+    }
+    out.trim_end().to_string()
+}
+
+/// Shallow-clones `repo` into the cheats directory the first time, or pulls
+/// latest changes on subsequent syncs. The first step is the same shallow
+/// clone `TldrStrategy` makes of the tldr-pages repo, which never pulls.
+pub fn sync(runner: &CommandRunner, repo: &str) -> Result<String> {
+    let dir = cheats_dir()?;
+
+    if dir.join(".git").is_dir() {
+        runner.run_git("git", &["-C", &dir.to_string_lossy(), "pull", "--ff-only"])?;
+        return Ok(format!("pulled latest cheats into {}", dir.display()));
+    }
+
+    if let Some(parent) = dir.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    runner.run_git("git", &["clone", "--depth", "1", repo, &dir.to_string_lossy()])?;
+    Ok(format!("cloned {repo} into {}", dir.display()))
+}
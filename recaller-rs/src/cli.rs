@@ -19,7 +19,7 @@ pub struct Cli {
 #[derive(Debug, Subcommand)]
 pub enum Commands {
     /// Launch the Recaller UI for search & documentation
-    Run,
+    Run(RunArgs),
     /// Print the Recaller usage guide
     Usage,
     /// Fetch history sorted by time and frequency
@@ -28,10 +28,28 @@ pub enum Commands {
     Fs(FsArgs),
     /// Manage Recaller configuration settings
     Settings(SettingsArgs),
+    /// Print a shell snippet that binds a hotkey to insert a command onto
+    /// the current prompt line, instead of opening a new terminal
+    Init(InitArgs),
+    /// Manage personal command cheatsheets
+    Cheats(CheatsArgs),
     /// Print the current Recaller version
     Version,
 }
 
+#[derive(Debug, Args)]
+pub struct InitArgs {
+    /// Shell to generate the keybinding glue for (`zsh` or `bash`)
+    pub shell: String,
+}
+
+#[derive(Debug, Default, Args)]
+pub struct RunArgs {
+    /// Copy the selected command to the clipboard instead of printing it
+    #[arg(long)]
+    pub copy: bool,
+}
+
 #[derive(Debug, Args)]
 pub struct HistoryArgs {
     /// Match string prefix to look in history
@@ -89,4 +107,56 @@ pub struct SettingsArgs {
 pub enum SettingsCommand {
     /// List current configuration settings
     List,
+    /// Enable or disable help strategies that hit the network (cheat.sh)
+    SetOnline(SetOnlineArgs),
+    /// Evict expired entries from the on-disk help cache
+    CleanHelpCache,
+}
+
+#[derive(Debug, Args)]
+pub struct SetOnlineArgs {
+    /// `true` to allow cheat.sh lookups, `false` to stay offline
+    pub enabled: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct CheatsArgs {
+    #[command(subcommand)]
+    pub command: CheatsCommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CheatsCommand {
+    /// Save a command snippet under a tag's cheatsheet
+    Add(CheatsAddArgs),
+    /// List every cheatsheet and its saved snippets
+    List,
+    /// Open a tag's cheatsheet in $EDITOR, creating it if needed
+    Edit(CheatsEditArgs),
+    /// Shallow-clone or pull a git repo of `.cheat` files into the local cheats directory
+    Sync(CheatsSyncArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct CheatsAddArgs {
+    /// Tag the snippet belongs to, e.g. `git` or `docker`
+    pub tag: String,
+    /// Short description shown above the command template
+    #[arg(long)]
+    pub description: String,
+    /// Command template, e.g. `git checkout -b <branch>`
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, required = true)]
+    pub command: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct CheatsEditArgs {
+    /// Tag to edit; its `.cheat` file is created if it doesn't exist yet
+    pub tag: String,
+}
+
+#[derive(Debug, Args)]
+pub struct CheatsSyncArgs {
+    /// Git URL of a cheatsheet repo to clone/pull
+    pub repo: String,
 }
@@ -5,19 +5,24 @@ use std::path::PathBuf;
 use anyhow::{Context, Result, bail};
 use directories::BaseDirs;
 
+use crate::cheats;
 use crate::cli::{
-    FsArgs, FsCleanArgs, FsCommand, FsIndexArgs, HistoryArgs, SettingsArgs, SettingsCommand,
+    CheatsAddArgs, CheatsArgs, CheatsCommand, CheatsEditArgs, CheatsSyncArgs, FsArgs, FsCleanArgs,
+    FsCommand, FsIndexArgs, HistoryArgs, InitArgs, RunArgs, SettingsArgs, SettingsCommand,
 };
 use crate::config;
 use crate::fs::{CleanupOptions, FilesystemIndexer};
 use crate::help::usage_text;
+use crate::help_system::manager::HelpManager;
+use crate::help_system::runner::CommandRunner;
 use crate::history::get_suggestions;
+use crate::process;
 use crate::state::AppState;
 use crate::ui;
 use crate::version::VERSION;
 
-pub fn handle_run(state: &mut AppState) -> Result<()> {
-    ui::history::run(state)
+pub fn handle_run(state: &mut AppState, args: &RunArgs) -> Result<()> {
+    ui::history::run(state, args.copy)
 }
 
 pub fn handle_usage() {
@@ -26,9 +31,10 @@ pub fn handle_usage() {
 
 pub fn handle_history(state: &mut AppState, args: &HistoryArgs) -> Result<()> {
     let enable_fuzzing = state.config.history.enable_fuzzing;
+    let frecency = state.config.history.frecency.clone();
     let index = state.history_index()?;
     let query = args.matcher.trim();
-    let suggestions = get_suggestions(index, query, enable_fuzzing);
+    let suggestions = get_suggestions(index, query, enable_fuzzing, &frecency);
 
     if suggestions.is_empty() {
         println!("No matching history entries found.");
@@ -219,11 +225,115 @@ fn handle_fs_refresh(state: &AppState) -> Result<()> {
     Ok(())
 }
 
+/// Prints a shell snippet that binds `^G` to a widget running `recaller
+/// run` and dropping the chosen command onto the current prompt line,
+/// rather than `send_to_terminal`'s "open a new terminal" approach. Meant
+/// to be `eval`-ed from `.zshrc`/`.bashrc`, e.g. `eval "$(recaller init zsh)"`.
+pub fn handle_init(args: &InitArgs) -> Result<()> {
+    match args.shell.as_str() {
+        "zsh" => {
+            println!("{}", ZSH_INIT_SCRIPT);
+            Ok(())
+        }
+        "bash" => {
+            println!("{}", BASH_INIT_SCRIPT);
+            Ok(())
+        }
+        other => bail!("unsupported shell '{other}' (expected 'zsh' or 'bash')"),
+    }
+}
+
+const ZSH_INIT_SCRIPT: &str = r#"_recaller_widget() {
+  local selected
+  selected=$(recaller run 2>/dev/tty | sed '/^$/d' | tail -n1)
+  if [[ -n "$selected" ]]; then
+    BUFFER="$selected"
+    CURSOR=${#BUFFER}
+  fi
+  zle redisplay
+}
+zle -N _recaller_widget
+bindkey '^G' _recaller_widget"#;
+
+const BASH_INIT_SCRIPT: &str = r#"_recaller_widget() {
+  local selected
+  selected=$(recaller run 2>/dev/tty | sed '/^$/d' | tail -n1)
+  if [[ -n "$selected" ]]; then
+    READLINE_LINE="$selected"
+    READLINE_POINT=${#READLINE_LINE}
+  fi
+}
+bind -x '"\C-g": _recaller_widget'"#;
+
+pub fn handle_cheats(args: &CheatsArgs) -> Result<()> {
+    match &args.command {
+        CheatsCommand::Add(add_args) => handle_cheats_add(add_args),
+        CheatsCommand::List => handle_cheats_list(),
+        CheatsCommand::Edit(edit_args) => handle_cheats_edit(edit_args),
+        CheatsCommand::Sync(sync_args) => handle_cheats_sync(sync_args),
+    }
+}
+
+fn handle_cheats_add(args: &CheatsAddArgs) -> Result<()> {
+    let command = args.command.join(" ");
+    let path = cheats::add_entry(&args.tag, &args.description, &command)?;
+    println!("✅ Saved to {}", path.display());
+    Ok(())
+}
+
+fn handle_cheats_list() -> Result<()> {
+    let sheets = cheats::load_all()?;
+    if sheets.is_empty() {
+        println!(
+            "📭 No cheatsheets yet. Add one with 'recaller cheats add <tag> --description \"...\" -- <command>'."
+        );
+        return Ok(());
+    }
+
+    for sheet in &sheets {
+        println!("{}\n", cheats::render(sheet));
+    }
+    Ok(())
+}
+
+fn handle_cheats_edit(args: &CheatsEditArgs) -> Result<()> {
+    let path = cheats::ensure_file(&args.tag)?;
+    let editor = env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+    let path_str = path.to_string_lossy().to_string();
+    let status = process::run_interactive(&[&editor, &path_str])?;
+    if !status.success() {
+        bail!("editor exited with {status}");
+    }
+    Ok(())
+}
+
+fn handle_cheats_sync(args: &CheatsSyncArgs) -> Result<()> {
+    let runner = CommandRunner::new();
+    let message = cheats::sync(&runner, &args.repo)?;
+    println!("✅ {message}");
+    Ok(())
+}
+
 pub fn handle_settings(args: &SettingsArgs) -> Result<()> {
-    match args.command {
+    match &args.command {
         SettingsCommand::List => {
             config::display_settings()?;
         }
+        SettingsCommand::SetOnline(set_args) => {
+            let mut cfg = config::load_config()?;
+            cfg.help.online = set_args.enabled;
+            config::save_config(&cfg)?;
+            println!(
+                "help.online set to {} (cheat.sh lookups are now {})",
+                set_args.enabled,
+                if set_args.enabled { "enabled" } else { "disabled" }
+            );
+        }
+        SettingsCommand::CleanHelpCache => {
+            let cfg = config::load_config()?;
+            let removed = HelpManager::purge_stale_cache(&cfg.help)?;
+            println!("removed {} stale help cache entries", removed);
+        }
     }
     Ok(())
 }
@@ -6,11 +6,56 @@ use directories::BaseDirs;
 use serde::{Deserialize, Serialize};
 
 use crate::constants::{GREEN, RESET};
+use crate::keymap::FilesystemKeymapConfig;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HistoryConfig {
     #[serde(default = "default_enable_fuzzing")]
     pub enable_fuzzing: bool,
+    #[serde(default)]
+    pub frecency: FrecencyConfig,
+    /// Forces a specific history source (`"zsh"`, `"bash"`, `"fish"`, or
+    /// `"atuin"`) instead of detecting it from `$SHELL`.
+    #[serde(default)]
+    pub source_override: Option<String>,
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+}
+
+/// Patterns that keep sensitive commands (API keys, tokens, `export
+/// AWS_SECRET_...`) out of the history index entirely, so they can never
+/// surface in search, the clipboard, or terminal injection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionConfig {
+    /// Regexes checked against each command; any match drops the entry.
+    #[serde(default = "default_redaction_patterns")]
+    pub patterns: Vec<String>,
+    /// Commands dropped only on an exact (trimmed) match, e.g. `history -c`.
+    #[serde(default = "default_redaction_exact")]
+    pub exact: Vec<String>,
+}
+
+/// Tunable thresholds/multipliers for the zoxide-style frecency score:
+/// `rank * age_factor`, where `age_factor` is picked by which bucket the
+/// command's last-seen time falls into (`recent_hours` wins over
+/// `day_hours` wins over `week_hours`; anything older gets
+/// `stale_multiplier`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrecencyConfig {
+    #[serde(default = "default_frecency_recent_hours")]
+    pub recent_hours: f64,
+    #[serde(default = "default_frecency_day_hours")]
+    pub day_hours: f64,
+    #[serde(default = "default_frecency_week_hours")]
+    pub week_hours: f64,
+    #[serde(default = "default_frecency_recent_multiplier")]
+    pub recent_multiplier: f64,
+    #[serde(default = "default_frecency_day_multiplier")]
+    pub day_multiplier: f64,
+    #[serde(default = "default_frecency_week_multiplier")]
+    pub week_multiplier: f64,
+    #[serde(default = "default_frecency_stale_multiplier")]
+    pub stale_multiplier: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +80,90 @@ pub struct FilesystemConfig {
     pub auto_index_on_startup: bool,
     #[serde(default = "default_index_cache_duration")]
     pub index_cache_duration_hours: u32,
+    #[serde(default = "default_preview_byte_budget")]
+    pub preview_byte_budget: usize,
+    #[serde(default = "default_preview_tab_width")]
+    pub preview_tab_width: usize,
+    #[serde(default)]
+    pub keymap: FilesystemKeymapConfig,
+    #[serde(default = "default_trash_enabled")]
+    pub trash_enabled: bool,
+    /// Hashing backend behind the index's bloom filter. `Xxh3` is
+    /// substantially faster than `Murmur3` on modern CPUs for the path
+    /// volumes this crate indexes; `Murmur3` stays the default so existing
+    /// on-disk indexes keep reading without a forced re-index.
+    #[serde(default)]
+    pub hash_backend: HashBackend,
+    /// Bit addressing scheme for the bloom filter. `Blocked` confines every
+    /// probe for a given key to one 256-byte block (four cache lines), trading a slightly
+    /// higher false-positive rate for far fewer cache misses on large
+    /// indexes; `Unblocked` stays the default since it's what every
+    /// existing on-disk index was built with.
+    #[serde(default)]
+    pub bloom_layout: BloomLayout,
+}
+
+/// Which hash function seeds the bloom filter's double hashing. Recorded in
+/// the on-disk index header (see `FilesystemIndexer::save_to_file`) so a
+/// reader never mixes digests from two different backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashBackend {
+    #[default]
+    Murmur3,
+    Xxh3,
+}
+
+/// How a `BloomFilter`'s `k` probes are scattered across its bit array. See
+/// `hash_backend` for the sibling knob controlling which hash seeds them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BloomLayout {
+    /// Every probe scatters across the full bit array (the classic
+    /// layout).
+    #[default]
+    Unblocked,
+    /// One base hash picks a single 256-byte (four cache line) block; every other
+    /// probe stays within that block.
+    Blocked,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomHelpCommand {
+    /// The base command this entry provides help for, e.g. `terraform`.
+    pub base_cmd: String,
+    /// Argument template rendered before execution. Supports `{cmd}` (the
+    /// base command) and `{subcmds}` (the remaining sub-command words),
+    /// e.g. `{cmd} {subcmds} --help` or `{cmd} help {subcmds}`.
+    pub template: String,
+    /// Environment variables to set while running the templated command,
+    /// mirroring `GitHelpStrategy`'s `GIT_PAGER=cat` trick.
+    #[serde(default)]
+    pub env: Vec<(String, String)>,
+    /// Lower values are tried first, same convention as `HelpStrategy::priority`.
+    #[serde(default = "default_custom_command_priority")]
+    pub priority: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelpConfig {
+    #[serde(default = "default_help_cache_duration")]
+    pub cache_duration_hours: u32,
+    #[serde(default)]
+    pub custom_commands: Vec<CustomHelpCommand>,
+    /// Whether strategies that hit the network (cheat.sh) are allowed to
+    /// run. Set to `false` for air-gapped use; cached cheat.sh pages still
+    /// work offline either way.
+    #[serde(default = "default_help_online")]
+    pub online: bool,
+    /// Maximum number of resolved-help entries kept in the persistent
+    /// `HelpCache`; the oldest entries are evicted once this is exceeded.
+    #[serde(default = "default_help_cache_max_entries")]
+    pub cache_max_entries: usize,
+    /// Where `TldrStrategy` keeps its local clone of the tldr-pages repo.
+    /// Falls back to `~/.cache/recaller/tldr` when unset.
+    #[serde(default)]
+    pub tldr_repo_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,13 +173,61 @@ pub struct Config {
     #[serde(default)]
     pub filesystem: FilesystemConfig,
     #[serde(default)]
+    pub help: HelpConfig,
+    #[serde(default)]
+    pub terminal: TerminalConfig,
+    #[serde(default)]
     pub quiet: bool,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TerminalConfig {
+    #[serde(default)]
+    pub target: TerminalTarget,
+}
+
+/// Where `send_to_terminal` delivers a selected command. `Auto` detects a
+/// running tmux/screen multiplexer and injects into the current pane there,
+/// falling back to spawning a new GUI terminal window otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalTarget {
+    #[default]
+    Auto,
+    NewWindow,
+    CurrentPane,
+}
+
 impl Default for HistoryConfig {
     fn default() -> Self {
         Self {
             enable_fuzzing: default_enable_fuzzing(),
+            frecency: FrecencyConfig::default(),
+            source_override: None,
+            redaction: RedactionConfig::default(),
+        }
+    }
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_redaction_patterns(),
+            exact: default_redaction_exact(),
+        }
+    }
+}
+
+impl Default for FrecencyConfig {
+    fn default() -> Self {
+        Self {
+            recent_hours: default_frecency_recent_hours(),
+            day_hours: default_frecency_day_hours(),
+            week_hours: default_frecency_week_hours(),
+            recent_multiplier: default_frecency_recent_multiplier(),
+            day_multiplier: default_frecency_day_multiplier(),
+            week_multiplier: default_frecency_week_multiplier(),
+            stale_multiplier: default_frecency_stale_multiplier(),
         }
     }
 }
@@ -68,6 +245,24 @@ impl Default for FilesystemConfig {
             sketch_depth: default_sketch_depth(),
             auto_index_on_startup: default_auto_index(),
             index_cache_duration_hours: default_index_cache_duration(),
+            preview_byte_budget: default_preview_byte_budget(),
+            preview_tab_width: default_preview_tab_width(),
+            keymap: FilesystemKeymapConfig::default(),
+            trash_enabled: default_trash_enabled(),
+            hash_backend: HashBackend::default(),
+            bloom_layout: BloomLayout::default(),
+        }
+    }
+}
+
+impl Default for HelpConfig {
+    fn default() -> Self {
+        Self {
+            cache_duration_hours: default_help_cache_duration(),
+            custom_commands: Vec::new(),
+            online: default_help_online(),
+            cache_max_entries: default_help_cache_max_entries(),
+            tldr_repo_path: None,
         }
     }
 }
@@ -77,6 +272,8 @@ impl Default for Config {
         Self {
             history: HistoryConfig::default(),
             filesystem: FilesystemConfig::default(),
+            help: HelpConfig::default(),
+            terminal: TerminalConfig::default(),
             quiet: false,
         }
     }
@@ -86,6 +283,49 @@ const fn default_enable_fuzzing() -> bool {
     true
 }
 
+const fn default_frecency_recent_hours() -> f64 {
+    1.0
+}
+
+const fn default_frecency_day_hours() -> f64 {
+    24.0
+}
+
+const fn default_frecency_week_hours() -> f64 {
+    24.0 * 7.0
+}
+
+const fn default_frecency_recent_multiplier() -> f64 {
+    4.0
+}
+
+const fn default_frecency_day_multiplier() -> f64 {
+    2.0
+}
+
+const fn default_frecency_week_multiplier() -> f64 {
+    0.5
+}
+
+const fn default_frecency_stale_multiplier() -> f64 {
+    0.25
+}
+
+fn default_redaction_patterns() -> Vec<String> {
+    vec![
+        r"(?i)password\s*=".to_string(),
+        r"(?i)token".to_string(),
+        r"(?i)secret".to_string(),
+        r"(?i)api[_-]?key".to_string(),
+        r"(?i)authorization:\s*bearer\s".to_string(),
+        r"(?i)\bbearer\s+[a-z0-9._-]+".to_string(),
+    ]
+}
+
+fn default_redaction_exact() -> Vec<String> {
+    vec!["history -c".to_string()]
+}
+
 const fn default_fs_enabled() -> bool {
     false
 }
@@ -139,6 +379,34 @@ const fn default_index_cache_duration() -> u32 {
     24
 }
 
+const fn default_preview_byte_budget() -> usize {
+    64 * 1024
+}
+
+const fn default_preview_tab_width() -> usize {
+    4
+}
+
+const fn default_trash_enabled() -> bool {
+    true
+}
+
+const fn default_help_cache_duration() -> u32 {
+    24
+}
+
+const fn default_help_online() -> bool {
+    true
+}
+
+const fn default_help_cache_max_entries() -> usize {
+    500
+}
+
+const fn default_custom_command_priority() -> i32 {
+    3
+}
+
 pub fn load_config() -> Result<Config> {
     let (cfg, _) = load_config_with_status()?;
     Ok(cfg)
@@ -171,6 +439,17 @@ pub fn create_default_config_file() -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Persists `cfg` back to the config file, overwriting it wholesale. Used by
+/// `recaller settings` subcommands that toggle a single flag rather than
+/// hand-editing the YAML.
+pub fn save_config(cfg: &Config) -> Result<()> {
+    let path = config_path()?;
+    let yaml = serde_yaml::to_string(cfg)?;
+    fs::write(&path, yaml)
+        .with_context(|| format!("failed to write configuration to {}", path.display()))?;
+    Ok(())
+}
+
 pub fn display_settings() -> Result<()> {
     let path = config_path()?;
     let (mut config, existed) = load_config_with_status()?;
@@ -0,0 +1,5 @@
+mod indexer;
+pub mod mounts;
+pub mod watcher;
+
+pub use indexer::{CleanupOptions, CleanupStats, FileMetadata, FilesystemIndexer, RankedFile};
@@ -1,21 +1,36 @@
 use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::{Read, Write};
+use std::io::{Cursor, Read, Write};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result, anyhow, bail};
 use chrono::{DateTime, Local, Utc};
 use directories::BaseDirs;
 use indicatif::{ProgressBar, ProgressStyle};
+use rayon::prelude::*;
 use walkdir::WalkDir;
+use xxhash_rust::xxh3::{xxh3_64, xxh3_128};
 
-use crate::config::FilesystemConfig;
+use crate::config::{BloomLayout, FilesystemConfig, HashBackend};
 
-const MAX_PATH_LENGTH: usize = 512;
+// Pre-v4 on-disk layouts stored paths inline as a fixed-size, nul-padded
+// buffer; kept only so `load_from_file` can still parse those files.
+const LEGACY_MAX_PATH_LENGTH: usize = 512;
 const COUNT_MIN_WIDTH: usize = 2048;
 const COUNT_MIN_DEPTH: usize = 4;
-const PATH_RECORD_SIZE: usize = MAX_PATH_LENGTH + 8 + 4 + 1; // 525 bytes
+// Fixed-size portion of a `PathRecord`: path_offset(4) + path_len(2) +
+// timestamp(8) + access_count(4) + flags(1) + dir_mtime(8) + size(8) +
+// mtime(8). The path bytes themselves live in the separate `path_arena`
+// blob, not in this record.
+const RECORD_SIZE: usize = 4 + 2 + 8 + 4 + 1 + 8 + 8 + 8; // 43 bytes
+
+// File entries are buffered up to this many before their `symlink_metadata`
+// flags are resolved in parallel via rayon.
+const PARALLEL_BATCH_SIZE: usize = 256;
+// Batches smaller than this run inline instead of through the thread pool;
+// below this size dispatch overhead outweighs the parallelism win.
+const PARALLEL_BATCH_MIN: usize = 64;
 
 const FLAG_IS_DIRECTORY: u8 = 1 << 0;
 const FLAG_IS_HIDDEN: u8 = 1 << 1;
@@ -60,29 +75,58 @@ pub struct CleanupStats {
 }
 
 pub struct FilesystemIndexer {
-    bloom_filter: BloomFilter,
+    bloom_filter: ScalableBloom,
     count_min: CountMinSketch,
     path_records: Vec<PathRecord>,
+    /// Backing storage for every path referenced by `path_records`; each
+    /// record stores an `(offset, len)` slice into this arena instead of a
+    /// fixed-size inline buffer, so path length isn't capped and short
+    /// paths don't pay for the longest possible one.
+    path_arena: Vec<u8>,
     path_index: HashMap<String, usize>,
     root_paths: Vec<String>,
     pub config: FilesystemConfig,
     is_dirty: bool,
+    /// Compiled once from `config.ignore_patterns` so the directory walk
+    /// doesn't rebuild the Aho-Corasick automaton on every candidate path.
+    pattern_matcher: PatternMatcher,
 }
 
 impl FilesystemIndexer {
     pub fn new(config: FilesystemConfig) -> Self {
-        let bloom_filter = BloomFilter::new(config.bloom_filter_size, config.bloom_filter_hashes);
+        let bloom_filter = ScalableBloom::new(
+            config.bloom_filter_size,
+            config.bloom_filter_hashes,
+            config.max_indexed_files as u64,
+            config.hash_backend,
+            config.bloom_layout,
+        );
+        let pattern_matcher = PatternMatcher::new(&config.ignore_patterns);
         Self {
             bloom_filter,
             count_min: CountMinSketch::new(),
             path_records: Vec::with_capacity(config.max_indexed_files),
+            path_arena: Vec::new(),
             path_index: HashMap::new(),
             root_paths: Vec::new(),
             config,
             is_dirty: false,
+            pattern_matcher,
         }
     }
 
+    /// Appends `path` to the arena and returns the `(offset, len)` slice a
+    /// `PathRecord` should store. Paths longer than `u16::MAX` are
+    /// truncated, which in practice never triggers on real filesystem
+    /// paths.
+    fn intern_path(&mut self, path: &str) -> (u32, u16) {
+        let offset = self.path_arena.len() as u32;
+        let bytes = path.as_bytes();
+        let len = bytes.len().min(u16::MAX as usize);
+        self.path_arena.extend_from_slice(&bytes[..len]);
+        (offset, len as u16)
+    }
+
     pub fn add_path(
         &mut self,
         path: &str,
@@ -107,38 +151,112 @@ impl FilesystemIndexer {
                 return;
             }
 
-            let flags = match fs::symlink_metadata(path) {
-                Ok(meta) => file_flags(&meta, Path::new(path)),
-                Err(_) => 0,
-            };
+            let meta = fs::symlink_metadata(path).ok();
+            let flags = meta
+                .as_ref()
+                .map(|m| file_flags(m, Path::new(path)))
+                .unwrap_or(0);
+            let size = meta.as_ref().map(|m| m.len()).unwrap_or(0);
+            let mtime = meta
+                .as_ref()
+                .and_then(|m| m.modified().ok())
+                .map(system_time_to_secs)
+                .unwrap_or(0);
+
+            self.insert_new_record(path, flags, size, mtime, timestamp, increment_access);
+        }
 
-            let ts = timestamp.map(|t| t.timestamp()).unwrap_or_else(|| {
-                if increment_access {
-                    Utc::now().timestamp()
-                } else {
-                    0
-                }
-            });
+        self.bloom_filter.add(path.as_bytes());
+        if increment_access {
+            self.count_min.add(path, 1);
+        }
+        self.is_dirty = true;
+    }
 
-            let mut access = 0;
-            if increment_access {
-                access = self.count_min.estimate(path);
-                if access == 0 {
-                    access = 1;
-                }
+    /// Inserts a path whose `flags`/`size`/`mtime` were already computed
+    /// elsewhere (e.g. by a `rayon` worker), skipping the
+    /// `fs::symlink_metadata` call `add_path` would otherwise make. Existing
+    /// entries are left untouched beyond the bloom filter touch, matching
+    /// `add_path`'s no-access-increment path.
+    fn add_path_with_metadata(&mut self, path: &str, flags: u8, size: u64, mtime: i64) {
+        if !self.path_index.contains_key(path) {
+            if self.path_records.len() >= self.config.max_indexed_files {
+                return;
             }
-
-            let record = PathRecord::new(path, ts, access, flags);
-            let idx = self.path_records.len();
-            self.path_index.insert(path.to_string(), idx);
-            self.path_records.push(record);
+            self.insert_new_record(path, flags, size, mtime, None, false);
         }
 
         self.bloom_filter.add(path.as_bytes());
+        self.is_dirty = true;
+    }
+
+    fn insert_new_record(
+        &mut self,
+        path: &str,
+        flags: u8,
+        size: u64,
+        mtime: i64,
+        timestamp: Option<DateTime<Utc>>,
+        increment_access: bool,
+    ) {
+        let ts = timestamp.map(|t| t.timestamp()).unwrap_or_else(|| {
+            if increment_access {
+                Utc::now().timestamp()
+            } else {
+                0
+            }
+        });
+
+        let mut access = 0;
         if increment_access {
-            self.count_min.add(path, 1);
+            access = self.count_min.estimate(path);
+            if access == 0 {
+                access = 1;
+            }
+        }
+
+        let (path_offset, path_len) = self.intern_path(path);
+        let record = PathRecord::new(path_offset, path_len, ts, access, flags, size, mtime);
+        let idx = self.path_records.len();
+        self.path_index.insert(path.to_string(), idx);
+        self.path_records.push(record);
+    }
+
+    /// Resolves flags/size/mtime for a batch of file entries off-thread via
+    /// `rayon`, then merges the results into `path_records`/`path_index`/
+    /// `bloom_filter` on the main thread. Small batches skip the thread pool
+    /// entirely since the dispatch overhead isn't worth it.
+    fn index_file_batch(&mut self, batch: Vec<walkdir::DirEntry>) {
+        if batch.len() < PARALLEL_BATCH_MIN {
+            for entry in batch {
+                let path_str = entry.path().to_string_lossy().to_string();
+                self.add_path(&path_str, None, false);
+            }
+            return;
+        }
+
+        let resolved: Vec<(String, u8, u64, i64)> = batch
+            .par_iter()
+            .map(|entry| {
+                let path_str = entry.path().to_string_lossy().to_string();
+                let meta = fs::symlink_metadata(entry.path()).ok();
+                let flags = meta
+                    .as_ref()
+                    .map(|m| file_flags(m, entry.path()))
+                    .unwrap_or(0);
+                let size = meta.as_ref().map(|m| m.len()).unwrap_or(0);
+                let mtime = meta
+                    .as_ref()
+                    .and_then(|m| m.modified().ok())
+                    .map(system_time_to_secs)
+                    .unwrap_or(0);
+                (path_str, flags, size, mtime)
+            })
+            .collect();
+
+        for (path_str, flags, size, mtime) in resolved {
+            self.add_path_with_metadata(&path_str, flags, size, mtime);
         }
-        self.is_dirty = true;
     }
 
     pub fn index_directories_with_progress(
@@ -165,7 +283,9 @@ impl FilesystemIndexer {
 
             self.add_root_path(path);
 
-            for entry in WalkDir::new(path) {
+            let mut walker = WalkDir::new(path).into_iter();
+            let mut file_batch: Vec<walkdir::DirEntry> = Vec::with_capacity(PARALLEL_BATCH_SIZE);
+            while let Some(entry) = walker.next() {
                 let entry = match entry {
                     Ok(e) => e,
                     Err(err) => {
@@ -191,8 +311,41 @@ impl FilesystemIndexer {
                     }
                 }
 
-                let path_str = entry.path().to_string_lossy().to_string();
-                self.add_path(&path_str, None, false);
+                if entry.file_type().is_dir() {
+                    let path_str = entry.path().to_string_lossy().to_string();
+                    let live_mtime = fs::metadata(entry.path())
+                        .and_then(|m| m.modified())
+                        .map(system_time_to_secs)
+                        .ok();
+
+                    if let Some(&existing_idx) = self.path_index.get(&path_str) {
+                        let stored_mtime = self.path_records[existing_idx].dir_mtime;
+                        if stored_mtime != 0 && live_mtime == Some(stored_mtime) {
+                            // Directory hasn't changed since it was last fully
+                            // walked: its children are already in
+                            // path_records, so don't descend into it again.
+                            walker.skip_current_dir();
+                            continue;
+                        }
+                    }
+
+                    self.add_path(&path_str, None, false);
+                    if let Some(mtime) = live_mtime {
+                        if let Some(&existing_idx) = self.path_index.get(&path_str) {
+                            self.path_records[existing_idx].dir_mtime = mtime;
+                        }
+                    }
+                    continue;
+                }
+
+                file_batch.push(entry);
+                if file_batch.len() >= PARALLEL_BATCH_SIZE {
+                    self.index_file_batch(std::mem::take(&mut file_batch));
+                }
+            }
+
+            if !file_batch.is_empty() {
+                self.index_file_batch(std::mem::take(&mut file_batch));
             }
         }
 
@@ -276,7 +429,7 @@ impl FilesystemIndexer {
         let query_lower = query.to_lowercase();
 
         for record in &self.path_records {
-            let path = record.path();
+            let path = record.path(&self.path_arena);
             let base = Path::new(&path)
                 .file_name()
                 .and_then(|s| s.to_str())
@@ -352,7 +505,7 @@ impl FilesystemIndexer {
                 bar.inc(1);
             }
 
-            let path = record.path();
+            let path = record.path(&self.path_arena);
             if let Some(prefix) = options.path.as_ref() {
                 if !path.starts_with(prefix) {
                     valid_records.push(*record);
@@ -392,26 +545,72 @@ impl FilesystemIndexer {
 
         if stats.removed_entries > 0 {
             self.rebuild_structures(valid_records, valid_paths);
-            stats.freed_kb = (stats.removed_entries * PATH_RECORD_SIZE) as f64 / 1024.0;
+            stats.freed_kb = (stats.removed_entries * RECORD_SIZE) as f64 / 1024.0;
         }
 
         Ok(stats)
     }
 
+    /// Removes a single path from the index (e.g. after it was moved to
+    /// trash or a watcher reports a delete). The counting bloom filter lets
+    /// this just decrement the removed path's counters instead of
+    /// rebuilding the whole filter from the surviving entries. Returns
+    /// `true` if the path was present.
+    pub fn remove_path(&mut self, path: &str) -> bool {
+        if !self.might_contain(path) {
+            return false;
+        }
+
+        let mut valid_records = Vec::with_capacity(self.path_records.len());
+        let mut valid_paths = Vec::with_capacity(self.path_records.len());
+        for record in &self.path_records {
+            let record_path = record.path(&self.path_arena);
+            if record_path != path {
+                valid_records.push(*record);
+                valid_paths.push(record_path);
+            }
+        }
+
+        self.path_records = valid_records;
+        self.path_index.clear();
+        for (idx, record_path) in valid_paths.iter().enumerate() {
+            self.path_index.insert(record_path.clone(), idx);
+        }
+
+        self.bloom_filter.remove(path.as_bytes());
+        self.is_dirty = true;
+        true
+    }
+
+    /// Cheap existence probe ahead of the exact `path_index` lookup: a
+    /// bloom miss proves the path was never indexed without touching
+    /// `path_index`'s hash bucket at all, and a bloom hit still falls
+    /// through to `path_index` to rule out a false positive.
+    fn might_contain(&self, path: &str) -> bool {
+        if !self.bloom_filter.contains(path.as_bytes()) {
+            return false;
+        }
+        self.path_index.contains_key(path)
+    }
+
     pub fn clear_index(&mut self) {
         self.path_records.clear();
+        self.path_arena.clear();
         self.path_index.clear();
         self.root_paths.clear();
-        self.bloom_filter = BloomFilter::new(
+        self.bloom_filter = ScalableBloom::new(
             self.config.bloom_filter_size,
             self.config.bloom_filter_hashes,
+            self.config.max_indexed_files as u64,
+            self.config.hash_backend,
+            self.config.bloom_layout,
         );
         self.count_min = CountMinSketch::new();
         self.is_dirty = true;
     }
 
     pub fn get_index_stats(&self) -> String {
-        let record_bytes = self.path_records.len() * PATH_RECORD_SIZE;
+        let record_bytes = self.path_records.len() * RECORD_SIZE + self.path_arena.len();
         let sketch_bytes = COUNT_MIN_DEPTH * COUNT_MIN_WIDTH * 4;
         let bloom_bytes = self.bloom_filter.estimated_bytes();
         format!(
@@ -435,9 +634,12 @@ impl FilesystemIndexer {
             self.path_index.insert(path.clone(), idx);
         }
 
-        self.bloom_filter = BloomFilter::new(
+        self.bloom_filter = ScalableBloom::new(
             self.config.bloom_filter_size,
             self.config.bloom_filter_hashes,
+            self.config.max_indexed_files as u64,
+            self.config.hash_backend,
+            self.config.bloom_layout,
         );
         self.count_min = CountMinSketch::new();
         for path in paths {
@@ -454,13 +656,16 @@ impl FilesystemIndexer {
             .ok_or_else(|| anyhow!("path not found in index"))?;
         let record = self.path_records[idx];
 
+        // `size`/`mtime` were captured once at index time, so ranking never
+        // re-stats the filesystem and stays correct even if the file has
+        // since disappeared.
         let timestamp = record.timestamp_option();
-        let metadata = fs::metadata(path).ok();
-        let size = metadata.as_ref().map(|m| m.len());
-        let last_modified = metadata
-            .as_ref()
-            .and_then(|meta| meta.modified().ok())
-            .map(|st| DateTime::<Local>::from(st));
+        let size = Some(record.size);
+        let last_modified = if record.mtime > 0 {
+            DateTime::<Utc>::from_timestamp(record.mtime, 0).map(DateTime::<Local>::from)
+        } else {
+            None
+        };
 
         Ok(FileMetadata {
             path: path.to_string(),
@@ -493,20 +698,7 @@ impl FilesystemIndexer {
     }
 
     fn should_skip(&self, path: &Path) -> bool {
-        let base = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
-        for pattern in &self.config.ignore_patterns {
-            if wildcard_match(pattern, base) {
-                return true;
-            }
-        }
-
-        let path_str = path.to_string_lossy();
-        for pattern in &self.config.ignore_patterns {
-            if path_str.contains(pattern) {
-                return true;
-            }
-        }
-        false
+        self.pattern_matcher.is_match(path)
     }
 
     fn add_root_path(&mut self, path: &Path) {
@@ -524,26 +716,37 @@ impl FilesystemIndexer {
     }
 
     fn save_to_file(&mut self, path: &Path) -> Result<()> {
-        let mut file = File::create(path).context("failed to create filesystem index file")?;
-        file.write_all(b"RECALLER")?;
-        file.write_all(&2u32.to_le_bytes())?; // version
-        file.write_all(&(self.path_records.len() as u32).to_le_bytes())?;
-        file.write_all(&(self.root_paths.len() as u32).to_le_bytes())?;
-        file.write_all(&[0u8; 12])?; // reserved
+        // Buffer the whole payload so its checksum can be written into the
+        // header before the payload itself.
+        let mut body = Vec::new();
+        body.write_all(&(self.path_records.len() as u32).to_le_bytes())?;
+        body.write_all(&(self.root_paths.len() as u32).to_le_bytes())?;
 
         for root in &self.root_paths {
             let bytes = root.as_bytes();
-            file.write_all(&(bytes.len() as u32).to_le_bytes())?;
-            file.write_all(bytes)?;
+            body.write_all(&(bytes.len() as u32).to_le_bytes())?;
+            body.write_all(bytes)?;
         }
 
-        self.bloom_filter.write_to(&mut file)?;
-        self.count_min.write_to(&mut file)?;
+        self.bloom_filter.write_to(&mut body)?;
+        self.count_min.write_to(&mut body)?;
 
         for record in &self.path_records {
-            record.write_to(&mut file)?;
+            record.write_to(&mut body)?;
         }
 
+        body.write_all(&(self.path_arena.len() as u32).to_le_bytes())?;
+        body.write_all(&self.path_arena)?;
+
+        let checksum = checksum_bytes(&body);
+
+        let mut file = File::create(path).context("failed to create filesystem index file")?;
+        file.write_all(b"RECALLER")?;
+        file.write_all(&11u32.to_le_bytes())?; // version
+        file.write_all(&checksum.to_le_bytes())?; // payload checksum
+        file.write_all(&[0u8; 4])?; // reserved
+        file.write_all(&body)?;
+
         self.is_dirty = false;
         Ok(())
     }
@@ -559,49 +762,116 @@ impl FilesystemIndexer {
         let mut ver_buf = [0u8; 4];
         file.read_exact(&mut ver_buf)?;
         let version = u32::from_le_bytes(ver_buf);
-        if version != 1 && version != 2 {
+        if !(1..=11).contains(&version) {
             bail!("unsupported filesystem index version: {version}");
         }
 
+        // v5 introduced a payload checksum in the header: verify it before
+        // trusting anything that follows, and fall back to an empty index
+        // (rather than erroring out) so a torn or corrupted write self-heals
+        // on the next `refresh_index`/indexing run.
+        let mut reader: Box<dyn Read> = if version >= 5 {
+            let mut checksum_buf = [0u8; 8];
+            file.read_exact(&mut checksum_buf)?;
+            let expected_checksum = u64::from_le_bytes(checksum_buf);
+            let mut reserved = [0u8; 4];
+            file.read_exact(&mut reserved)?;
+
+            let mut body = Vec::new();
+            file.read_to_end(&mut body)?;
+            if checksum_bytes(&body) != expected_checksum {
+                eprintln!(
+                    "warning: filesystem index at {} failed its integrity check; starting from an empty index",
+                    path.display()
+                );
+                self.clear_index();
+                self.is_dirty = false;
+                return Ok(());
+            }
+
+            Box::new(Cursor::new(body))
+        } else {
+            Box::new(file)
+        };
+
         let mut count_buf = [0u8; 4];
-        file.read_exact(&mut count_buf)?;
+        reader.read_exact(&mut count_buf)?;
         let record_count = u32::from_le_bytes(count_buf);
 
-        let root_count = if version == 2 {
+        let root_count = if version >= 2 {
             let mut buf = [0u8; 4];
-            file.read_exact(&mut buf)?;
+            reader.read_exact(&mut buf)?;
             u32::from_le_bytes(buf)
         } else {
             let mut _bloom = [0u8; 4];
-            file.read_exact(&mut _bloom)?;
+            reader.read_exact(&mut _bloom)?;
             0
         };
 
-        let mut reserved = [0u8; 12];
-        file.read_exact(&mut reserved)?;
+        if version < 5 {
+            let mut reserved = [0u8; 12];
+            reader.read_exact(&mut reserved)?;
+        }
 
         self.root_paths.clear();
         for _ in 0..root_count {
             let mut len_buf = [0u8; 4];
-            file.read_exact(&mut len_buf)?;
+            reader.read_exact(&mut len_buf)?;
             let len = u32::from_le_bytes(len_buf) as usize;
             let mut buf = vec![0u8; len];
-            file.read_exact(&mut buf)?;
+            reader.read_exact(&mut buf)?;
             if let Ok(path) = String::from_utf8(buf) {
                 self.root_paths.push(path);
             }
         }
 
-        self.bloom_filter.read_from(&mut file)?;
-        self.count_min.read_from(&mut file)?;
+        self.bloom_filter.read_from(&mut reader, version)?;
+        self.count_min.read_from(&mut reader)?;
 
         self.path_records.clear();
+        self.path_arena.clear();
         self.path_index.clear();
-        for i in 0..record_count {
-            let record = PathRecord::read_from(&mut file)?;
-            let path = record.path();
-            self.path_index.insert(path.clone(), i as usize);
-            self.path_records.push(record);
+
+        if version >= 4 {
+            for _ in 0..record_count {
+                let record = PathRecord::read_from(&mut reader, version)?;
+                self.path_records.push(record);
+            }
+
+            let mut blob_len_buf = [0u8; 4];
+            reader.read_exact(&mut blob_len_buf)?;
+            let blob_len = u32::from_le_bytes(blob_len_buf) as usize;
+            let mut arena = vec![0u8; blob_len];
+            reader.read_exact(&mut arena)?;
+            self.path_arena = arena;
+
+            for (i, record) in self.path_records.iter().enumerate() {
+                let path = record.path(&self.path_arena);
+                self.path_index.insert(path, i);
+            }
+        } else {
+            for i in 0..record_count {
+                let (path_bytes, timestamp, access_count, flags, dir_mtime) =
+                    PathRecord::read_legacy(&mut reader, version)?;
+
+                let path_offset = self.path_arena.len() as u32;
+                let path_len = path_bytes.len().min(u16::MAX as usize);
+                self.path_arena.extend_from_slice(&path_bytes[..path_len]);
+
+                let record = PathRecord {
+                    path_offset,
+                    path_len: path_len as u16,
+                    timestamp,
+                    access_count,
+                    flags,
+                    dir_mtime,
+                    size: 0,
+                    mtime: 0,
+                };
+                let path = record.path(&self.path_arena);
+                self.path_index.insert(path, i as usize);
+                self.path_records.push(record);
+            }
         }
 
         self.is_dirty = false;
@@ -609,48 +879,90 @@ impl FilesystemIndexer {
     }
 }
 
+/// Checksums a byte buffer for the on-disk index's integrity check. Not
+/// cryptographic — just enough to catch a torn or truncated write. Uses
+/// XXH3 rather than `DefaultHasher` (SipHash) because `DefaultHasher`'s
+/// digest isn't contractually stable across std versions/builds; a stable
+/// digest is required since this value is persisted and compared on a
+/// later run, possibly under a different toolchain.
+fn checksum_bytes(data: &[u8]) -> u64 {
+    xxh3_64(data)
+}
+
 #[derive(Clone, Copy)]
 struct PathRecord {
-    path: [u8; MAX_PATH_LENGTH],
+    /// Offset and length of this record's path within the indexer's
+    /// `path_arena`, replacing the old fixed-size inline buffer.
+    path_offset: u32,
+    path_len: u16,
     timestamp: i64,
     access_count: i32,
     flags: u8,
+    /// Directory's `mtime` at the time it was last fully walked, used by
+    /// `index_directories_with_progress` to skip re-descending into
+    /// unchanged subtrees. `0` for non-directories or records loaded from a
+    /// pre-v3 index.
+    dir_mtime: i64,
+    /// File size and mtime captured from `symlink_metadata` at index time,
+    /// so `search_files` can rank and report them without a fresh `stat()`
+    /// per candidate. `0` if the file couldn't be stat'd when indexed or the
+    /// record came from a pre-v6 index.
+    size: u64,
+    mtime: i64,
 }
 
 impl PathRecord {
-    fn new(path: &str, timestamp: i64, access_count: i32, flags: u8) -> Self {
-        let mut buf = [0u8; MAX_PATH_LENGTH];
-        let bytes = path.as_bytes();
-        let len = bytes.len().min(MAX_PATH_LENGTH - 1);
-        buf[..len].copy_from_slice(&bytes[..len]);
+    fn new(
+        path_offset: u32,
+        path_len: u16,
+        timestamp: i64,
+        access_count: i32,
+        flags: u8,
+        size: u64,
+        mtime: i64,
+    ) -> Self {
         Self {
-            path: buf,
+            path_offset,
+            path_len,
             timestamp,
             access_count,
             flags,
+            dir_mtime: 0,
+            size,
+            mtime,
         }
     }
 
-    fn path(&self) -> String {
-        let end = self
-            .path
-            .iter()
-            .position(|&b| b == 0)
-            .unwrap_or(MAX_PATH_LENGTH);
-        String::from_utf8_lossy(&self.path[..end]).to_string()
+    fn path(&self, arena: &[u8]) -> String {
+        let start = self.path_offset as usize;
+        let end = start + self.path_len as usize;
+        String::from_utf8_lossy(&arena[start..end]).to_string()
     }
 
     fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
-        writer.write_all(&self.path)?;
+        writer.write_all(&self.path_offset.to_le_bytes())?;
+        writer.write_all(&self.path_len.to_le_bytes())?;
         writer.write_all(&self.timestamp.to_le_bytes())?;
         writer.write_all(&self.access_count.to_le_bytes())?;
         writer.write_all(&[self.flags])?;
+        writer.write_all(&self.dir_mtime.to_le_bytes())?;
+        writer.write_all(&self.size.to_le_bytes())?;
+        writer.write_all(&self.mtime.to_le_bytes())?;
         Ok(())
     }
 
-    fn read_from<R: Read>(mut reader: R) -> Result<Self> {
-        let mut path = [0u8; MAX_PATH_LENGTH];
-        reader.read_exact(&mut path)?;
+    /// Reads a v4+-layout record table entry. The path itself isn't here —
+    /// it's read separately from the `path_arena` blob that follows the
+    /// record table. `size`/`mtime` were added in v6, so earlier versions
+    /// default them to `0`.
+    fn read_from<R: Read>(mut reader: R, version: u32) -> Result<Self> {
+        let mut offset_buf = [0u8; 4];
+        reader.read_exact(&mut offset_buf)?;
+        let path_offset = u32::from_le_bytes(offset_buf);
+
+        let mut len_buf = [0u8; 2];
+        reader.read_exact(&mut len_buf)?;
+        let path_len = u16::from_le_bytes(len_buf);
 
         let mut ts_buf = [0u8; 8];
         reader.read_exact(&mut ts_buf)?;
@@ -663,14 +975,67 @@ impl PathRecord {
         let mut flag = [0u8; 1];
         reader.read_exact(&mut flag)?;
 
+        let mut dir_mtime_buf = [0u8; 8];
+        reader.read_exact(&mut dir_mtime_buf)?;
+        let dir_mtime = i64::from_le_bytes(dir_mtime_buf);
+
+        let (size, mtime) = if version >= 6 {
+            let mut size_buf = [0u8; 8];
+            reader.read_exact(&mut size_buf)?;
+            let mut mtime_buf = [0u8; 8];
+            reader.read_exact(&mut mtime_buf)?;
+            (u64::from_le_bytes(size_buf), i64::from_le_bytes(mtime_buf))
+        } else {
+            (0, 0)
+        };
+
         Ok(Self {
-            path,
+            path_offset,
+            path_len,
             timestamp,
             access_count,
             flags: flag[0],
+            dir_mtime,
+            size,
+            mtime,
         })
     }
 
+    /// Reads a record written in a pre-v4 layout, where the path was stored
+    /// inline as a fixed-size, nul-padded buffer rather than as an
+    /// arena offset. Returns the raw path bytes alongside the other
+    /// fields so the caller can intern them into the arena.
+    fn read_legacy<R: Read>(mut reader: R, version: u32) -> Result<(Vec<u8>, i64, i32, u8, i64)> {
+        let mut path = [0u8; LEGACY_MAX_PATH_LENGTH];
+        reader.read_exact(&mut path)?;
+        let end = path
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(LEGACY_MAX_PATH_LENGTH);
+        let path_bytes = path[..end].to_vec();
+
+        let mut ts_buf = [0u8; 8];
+        reader.read_exact(&mut ts_buf)?;
+        let timestamp = i64::from_le_bytes(ts_buf);
+
+        let mut access_buf = [0u8; 4];
+        reader.read_exact(&mut access_buf)?;
+        let access_count = i32::from_le_bytes(access_buf);
+
+        let mut flag = [0u8; 1];
+        reader.read_exact(&mut flag)?;
+
+        let dir_mtime = if version >= 3 {
+            let mut buf = [0u8; 8];
+            reader.read_exact(&mut buf)?;
+            i64::from_le_bytes(buf)
+        } else {
+            0
+        };
+
+        Ok((path_bytes, timestamp, access_count, flag[0], dir_mtime))
+    }
+
     fn timestamp_option(&self) -> Option<DateTime<Utc>> {
         if self.timestamp > 0 {
             DateTime::<Utc>::from_timestamp(self.timestamp, 0)
@@ -680,6 +1045,12 @@ impl PathRecord {
     }
 }
 
+fn system_time_to_secs(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
 struct CountMinSketch {
     table: [[i32; COUNT_MIN_WIDTH]; COUNT_MIN_DEPTH],
 }
@@ -737,79 +1108,489 @@ impl CountMinSketch {
 struct BloomFilter {
     m: u64,
     k: u64,
-    bitset: BitSet,
+    backend: HashBackend,
+    layout: BloomLayout,
+    /// Addressable bits per block under `BloomLayout::Blocked`. Recorded per
+    /// filter (rather than read off the `BLOCK_BITS` constant at query time)
+    /// so a file written by a build with a different block size still
+    /// addresses its own bits correctly instead of being silently misread.
+    block_bits: u64,
+    bitset: CountingBitSet,
 }
 
+/// Default addressable bits per block under `BloomLayout::Blocked` — one
+/// cache line in the classic (unpacked) bloom filter model this sizing is
+/// based on. `CountingBitSet` packs two 4-bit counters per byte, so a block
+/// this wide spans 256 bytes (four 64-byte cache lines) rather than one, but
+/// every probe for a key still lands in that one small region instead of
+/// scattering across the whole filter.
+const BLOCK_BITS: u64 = 512;
+
 impl BloomFilter {
-    fn new(m: u32, k: u32) -> Self {
+    fn new(m: u32, k: u32, backend: HashBackend, layout: BloomLayout) -> Self {
         let m = m.max(1) as u64;
         let k = k.max(1) as u64;
         Self {
             m,
             k,
-            bitset: BitSet::new(m),
+            backend,
+            layout,
+            block_bits: BLOCK_BITS,
+            bitset: CountingBitSet::new(m),
+        }
+    }
+
+    /// Picks the bit slot for probe `i` of an already-hashed key, honoring
+    /// `self.layout`.
+    fn slot(&self, hashes: &[u64; 4], i: u64) -> u64 {
+        match self.layout {
+            BloomLayout::Unblocked => location(hashes, i) % self.m,
+            BloomLayout::Blocked => blocked_location(hashes, i, self.m, self.block_bits),
         }
     }
 
     fn add(&mut self, data: &[u8]) {
-        let hashes = base_hashes(data);
+        let hashes = base_hashes(data, self.backend);
         for i in 0..self.k {
-            let idx = location(&hashes, i) % self.m;
-            self.bitset.set(idx);
+            let idx = self.slot(&hashes, i);
+            self.bitset.increment(idx);
         }
     }
 
-    fn read_from<R: Read>(&mut self, mut reader: R) -> Result<()> {
+    /// Decrements the same k counters `add` would have incremented for this
+    /// value. Counters that saturated (see `CountingBitSet::increment`) are
+    /// left alone rather than decremented, since we can no longer tell how
+    /// many inserts actually hashed there.
+    fn remove(&mut self, data: &[u8]) {
+        let hashes = base_hashes(data, self.backend);
+        for i in 0..self.k {
+            let idx = self.slot(&hashes, i);
+            self.bitset.decrement(idx);
+        }
+    }
+
+    /// `version` gates whether a backend byte, layout byte and
+    /// counting-filter payload are present in the stream: pre-v7 indexes
+    /// predate `HashBackend` and were always Murmur3, pre-v8 indexes stored
+    /// a plain bit vector instead of saturating counters, and pre-v10
+    /// indexes predate `BloomLayout` and were always unblocked.
+    fn read_from<R: Read>(&mut self, mut reader: R, version: u32) -> Result<()> {
         let mut m_buf = [0u8; 8];
         reader.read_exact(&mut m_buf)?;
         self.m = u64::from_be_bytes(m_buf);
         let mut k_buf = [0u8; 8];
         reader.read_exact(&mut k_buf)?;
         self.k = u64::from_be_bytes(k_buf);
-        self.bitset.read_from(&mut reader)?;
+        self.backend = if version >= 7 {
+            let mut backend_buf = [0u8; 1];
+            reader.read_exact(&mut backend_buf)?;
+            match backend_buf[0] {
+                1 => HashBackend::Xxh3,
+                _ => HashBackend::Murmur3,
+            }
+        } else {
+            HashBackend::Murmur3
+        };
+        self.layout = if version >= 10 {
+            let mut layout_buf = [0u8; 1];
+            reader.read_exact(&mut layout_buf)?;
+            match layout_buf[0] {
+                1 => BloomLayout::Blocked,
+                _ => BloomLayout::Unblocked,
+            }
+        } else {
+            BloomLayout::Unblocked
+        };
+        if version >= 8 {
+            self.bitset.read_from(&mut reader)?;
+        } else {
+            self.bitset = CountingBitSet::from_legacy_bitset(&mut reader)?;
+        }
         Ok(())
     }
 
     fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
         writer.write_all(&self.m.to_be_bytes())?;
         writer.write_all(&self.k.to_be_bytes())?;
+        let backend_byte: u8 = match self.backend {
+            HashBackend::Murmur3 => 0,
+            HashBackend::Xxh3 => 1,
+        };
+        writer.write_all(&[backend_byte])?;
+        let layout_byte: u8 = match self.layout {
+            BloomLayout::Unblocked => 0,
+            BloomLayout::Blocked => 1,
+        };
+        writer.write_all(&[layout_byte])?;
         self.bitset.write_to(&mut writer)?;
         Ok(())
     }
 
     fn estimated_bytes(&self) -> usize {
-        (self.bitset.data.len() * 8) + 16
+        self.bitset.data.len() + 18
+    }
+
+    fn contains(&self, data: &[u8]) -> bool {
+        let hashes = base_hashes(data, self.backend);
+        (0..self.k).all(|i| {
+            let idx = self.slot(&hashes, i);
+            self.bitset.get(idx) > 0
+        })
     }
 }
 
+/// Addresses a single probe under `BloomLayout::Blocked`: `hashes[0]` picks
+/// one `block_bits`-wide block out of `m`, and the remaining hashes (mirrors
+/// of `location`'s double-hashing formula, just confined to the block)
+/// place probe `i` within it. Every probe for the same key therefore lands
+/// in one block — 256 bytes of `CountingBitSet` at the default `BLOCK_BITS`,
+/// i.e. four cache lines — instead
+/// of scattering across all of `m`.
+fn blocked_location(h: &[u64; 4], i: u64, m: u64, block_bits: u64) -> u64 {
+    let block_bits = block_bits.min(m.max(1));
+    let num_blocks = ((m + block_bits - 1) / block_bits).max(1);
+    let block_idx = h[0] % num_blocks;
+    let in_block = h[1].wrapping_add(i.wrapping_mul(h[2])) % block_bits;
+    (block_idx * block_bits + in_block).min(m - 1)
+}
+
+/// A growth-aware wrapper around `BloomFilter` (see "Scalable Bloom
+/// Filters", Almeida et al.): a single fixed-size filter's false positive
+/// rate silently climbs once more entries land in it than it was planned
+/// for. Inserts that would overflow the newest slice instead spill into a
+/// freshly allocated slice sized for `SCALABLE_GROWTH_FACTOR` times the
+/// previous slice's capacity, at a tightened target error rate of `P_0 *
+/// SCALABLE_ERROR_RATIO^i`. Lookups and removals touch every slice; the
+/// compound false positive bound across all of them, `P_0 / (1 -
+/// SCALABLE_ERROR_RATIO)`, stays finite no matter how many slices
+/// accumulate.
+struct ScalableBloom {
+    slices: Vec<BloomFilter>,
+    slice_capacity: Vec<u64>,
+    slice_count: Vec<u64>,
+    initial_capacity: u64,
+    base_error_rate: f64,
+    backend: HashBackend,
+    layout: BloomLayout,
+}
+
+const SCALABLE_GROWTH_FACTOR: f64 = 2.0;
+const SCALABLE_ERROR_RATIO: f64 = 0.9;
+
+/// Magic bytes opening a self-describing `ScalableBloom` blob (index format
+/// v11+), distinct from the outer `RECALLER` index magic so a bloom section
+/// read out of alignment fails fast instead of silently misparsing.
+const BLOOM_MAGIC: &[u8; 4] = b"BLMF";
+/// Version of the self-describing header itself, independent of the outer
+/// index format version — lets the header evolve without forcing another
+/// bump to every other section's version gate.
+const BLOOM_HEADER_VERSION: u8 = 1;
+/// Filter variant recorded in the header. Only one variant is produced
+/// today; `read_from` rejects anything else with a clear error rather than
+/// guessing at a layout it doesn't understand.
+const BLOOM_VARIANT_SCALABLE_COUNTING: u8 = 1;
+
+impl ScalableBloom {
+    fn new(
+        bits: u32,
+        hashes: u32,
+        capacity: u64,
+        backend: HashBackend,
+        layout: BloomLayout,
+    ) -> Self {
+        let capacity = capacity.max(1);
+        let base_error_rate =
+            estimate_error_rate(bits.max(1) as u64, hashes.max(1) as u64, capacity);
+        Self {
+            slices: vec![BloomFilter::new(bits, hashes, backend, layout)],
+            slice_capacity: vec![capacity],
+            slice_count: vec![0],
+            initial_capacity: capacity,
+            base_error_rate,
+            backend,
+            layout,
+        }
+    }
+
+    /// Sizes a slice for `capacity` expected entries at `error_rate` false
+    /// positives, using the standard bloom filter formulas `m =
+    /// -n*ln(p)/ln(2)^2` for the bit count and `k = (m/n)*ln(2)` for the
+    /// number of hash rounds.
+    fn plan_slice(capacity: u64, error_rate: f64) -> (u32, u32) {
+        let n = capacity.max(1) as f64;
+        let p = error_rate.clamp(1e-6, 0.5);
+        let ln2 = std::f64::consts::LN_2;
+        let m = (-(n * p.ln()) / (ln2 * ln2)).ceil().max(1.0);
+        let k = ((m / n) * ln2).round().max(1.0);
+        (m as u32, k as u32)
+    }
+
+    fn grow(&mut self) {
+        let i = self.slices.len() as i32;
+        let capacity =
+            (self.initial_capacity as f64 * SCALABLE_GROWTH_FACTOR.powi(i)).ceil() as u64;
+        let error_rate = self.base_error_rate * SCALABLE_ERROR_RATIO.powi(i);
+        let (m, k) = Self::plan_slice(capacity, error_rate);
+        self.slices
+            .push(BloomFilter::new(m, k, self.backend, self.layout));
+        self.slice_capacity.push(capacity);
+        self.slice_count.push(0);
+    }
+
+    fn add(&mut self, data: &[u8]) {
+        let last = self.slices.len() - 1;
+        if self.slice_count[last] >= self.slice_capacity[last] {
+            self.grow();
+        }
+        let last = self.slices.len() - 1;
+        self.slices[last].add(data);
+        self.slice_count[last] += 1;
+    }
+
+    /// Broadcasts the removal to every slice. We don't track which slice a
+    /// given value was originally added to, but decrementing a slice it was
+    /// never added to is a guarded no-op (see `CountingBitSet::decrement`),
+    /// so this stays safe.
+    fn remove(&mut self, data: &[u8]) {
+        for slice in &mut self.slices {
+            slice.remove(data);
+        }
+    }
+
+    fn contains(&self, data: &[u8]) -> bool {
+        self.slices.iter().any(|slice| slice.contains(data))
+    }
+
+    fn estimated_bytes(&self) -> usize {
+        self.slices.iter().map(BloomFilter::estimated_bytes).sum()
+    }
+
+    /// Always emits the current self-describing format: a `BLOOM_MAGIC`
+    /// header recording the header version, hash backend, bit layout,
+    /// filter variant and dominant `k`, followed by a checksum over the
+    /// slice body. A reader on a future build that changed any of those
+    /// params can tell from the header alone rather than having to guess
+    /// from the bytes that follow.
+    fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
+        let mut body = Vec::new();
+        body.write_all(&(self.slices.len() as u32).to_le_bytes())?;
+        for (slice, &count) in self.slices.iter().zip(&self.slice_count) {
+            body.write_all(&count.to_le_bytes())?;
+            slice.write_to(&mut body)?;
+        }
+
+        writer.write_all(BLOOM_MAGIC)?;
+        writer.write_all(&[BLOOM_HEADER_VERSION])?;
+        let backend_byte: u8 = match self.backend {
+            HashBackend::Murmur3 => 0,
+            HashBackend::Xxh3 => 1,
+        };
+        writer.write_all(&[backend_byte])?;
+        let layout_byte: u8 = match self.layout {
+            BloomLayout::Unblocked => 0,
+            BloomLayout::Blocked => 1,
+        };
+        writer.write_all(&[layout_byte])?;
+        writer.write_all(&[BLOOM_VARIANT_SCALABLE_COUNTING])?;
+        // Derived rather than stored separately: slices can vary their own
+        // `k` as the filter grows (the first slice's is recorded for display/
+        // sanity-checks), while `block_bits` is shared by every slice and
+        // `read_from` applies this value to each one it rebuilds.
+        let k = self.slices.first().map(|s| s.k).unwrap_or(1);
+        let block_bits = self.slices.first().map(|s| s.block_bits).unwrap_or(0);
+        writer.write_all(&k.to_le_bytes())?;
+        writer.write_all(&block_bits.to_le_bytes())?;
+        writer.write_all(&checksum_bytes(&body).to_le_bytes())?;
+        writer.write_all(&body)?;
+        Ok(())
+    }
+
+    /// `version` gates the on-disk layout: pre-v9 indexes stored a single
+    /// `BloomFilter` directly, with no slice count prefix; v9-v10 prefixed
+    /// the slice table with a bare slice count; v11+ wraps that same slice
+    /// table in a self-describing, checksummed header (see `write_to`).
+    fn read_from<R: Read>(&mut self, mut reader: R, version: u32) -> Result<()> {
+        if version < 9 {
+            let mut slice = BloomFilter::new(1, 1, self.backend, self.layout);
+            slice.read_from(&mut reader, version)?;
+            self.slices = vec![slice];
+            self.slice_capacity = vec![self.initial_capacity];
+            // The legacy format never tracked an insert count, so there's
+            // no way to know how full this filter really was; treat it as
+            // freshly started and let growth be driven by inserts made
+            // after the upgrade instead of a guess.
+            self.slice_count = vec![0];
+            return Ok(());
+        }
+
+        let mut header_block_bits: Option<u64> = None;
+        let mut reader: Box<dyn Read> = if version >= 11 {
+            let mut magic = [0u8; 4];
+            reader.read_exact(&mut magic)?;
+            if &magic != BLOOM_MAGIC {
+                bail!("invalid bloom filter section (bad magic)");
+            }
+            let mut header_version = [0u8; 1];
+            reader.read_exact(&mut header_version)?;
+            if header_version[0] != BLOOM_HEADER_VERSION {
+                bail!(
+                    "unsupported bloom filter header version: {}",
+                    header_version[0]
+                );
+            }
+            let mut backend_byte = [0u8; 1];
+            reader.read_exact(&mut backend_byte)?;
+            self.backend = match backend_byte[0] {
+                0 => HashBackend::Murmur3,
+                1 => HashBackend::Xxh3,
+                other => bail!("unknown bloom filter hash backend id: {other}"),
+            };
+            let mut layout_byte = [0u8; 1];
+            reader.read_exact(&mut layout_byte)?;
+            self.layout = match layout_byte[0] {
+                0 => BloomLayout::Unblocked,
+                1 => BloomLayout::Blocked,
+                other => bail!("unknown bloom filter layout id: {other}"),
+            };
+            let mut variant_byte = [0u8; 1];
+            reader.read_exact(&mut variant_byte)?;
+            if variant_byte[0] != BLOOM_VARIANT_SCALABLE_COUNTING {
+                bail!("unknown bloom filter variant id: {}", variant_byte[0]);
+            }
+            let mut k_buf = [0u8; 8];
+            reader.read_exact(&mut k_buf)?;
+            let _k = u64::from_le_bytes(k_buf); // informational; each slice carries its own k too
+            let mut block_bits_buf = [0u8; 8];
+            reader.read_exact(&mut block_bits_buf)?;
+            header_block_bits = Some(u64::from_le_bytes(block_bits_buf));
+
+            let mut checksum_buf = [0u8; 8];
+            reader.read_exact(&mut checksum_buf)?;
+            let expected_checksum = u64::from_le_bytes(checksum_buf);
+
+            let mut body = Vec::new();
+            reader.read_to_end(&mut body)?;
+            if checksum_bytes(&body) != expected_checksum {
+                bail!("bloom filter section failed its integrity check");
+            }
+            Box::new(Cursor::new(body))
+        } else {
+            Box::new(reader)
+        };
+
+        let mut count_buf = [0u8; 4];
+        reader.read_exact(&mut count_buf)?;
+        let slice_total = u32::from_le_bytes(count_buf);
+
+        let mut slices = Vec::with_capacity(slice_total as usize);
+        let mut slice_count = Vec::with_capacity(slice_total as usize);
+        let mut slice_capacity = Vec::with_capacity(slice_total as usize);
+        for i in 0..slice_total {
+            let mut inserted_buf = [0u8; 8];
+            reader.read_exact(&mut inserted_buf)?;
+            let inserted = u64::from_le_bytes(inserted_buf);
+
+            let mut slice = BloomFilter::new(1, 1, self.backend, self.layout);
+            slice.read_from(&mut reader, version)?;
+            if let Some(bits) = header_block_bits.filter(|&b| b > 0) {
+                slice.block_bits = bits;
+            }
+
+            let capacity =
+                (self.initial_capacity as f64 * SCALABLE_GROWTH_FACTOR.powi(i as i32)).ceil()
+                    as u64;
+            slices.push(slice);
+            slice_count.push(inserted);
+            slice_capacity.push(capacity);
+        }
+
+        self.slices = slices;
+        self.slice_count = slice_count;
+        self.slice_capacity = slice_capacity;
+        Ok(())
+    }
+}
+
+/// Estimates a bloom filter's false positive rate for `n` expected entries
+/// given its bit count `m` and hash round count `k`, via the standard `(1 -
+/// e^(-kn/m))^k` approximation. Used to seed `ScalableBloom`'s per-slice
+/// error rate from the `bloom_filter_size`/`bloom_filter_hashes` config
+/// knobs that size its first slice.
+fn estimate_error_rate(m: u64, k: u64, n: u64) -> f64 {
+    let m = m as f64;
+    let k = k as f64;
+    let n = n.max(1) as f64;
+    (1.0 - (-k * n / m).exp()).powf(k).clamp(1e-6, 0.5)
+}
+
+/// Backing store for the bloom filter: 4-bit saturating counters packed two
+/// per byte, rather than a single bit per slot. This makes the filter
+/// support removal as well as insertion — membership is "all k counters
+/// nonzero," and a delete just decrements the same k counters an insert
+/// incremented, instead of needing to rebuild the whole filter from the
+/// surviving entries.
 #[derive(Clone)]
-struct BitSet {
+struct CountingBitSet {
     length: u64,
-    data: Vec<u64>,
+    data: Vec<u8>,
 }
 
-impl BitSet {
+const COUNTER_MAX: u8 = 0x0F;
+const COUNTER_WIDTH_BITS: u8 = 4;
+
+impl CountingBitSet {
     fn new(length: u64) -> Self {
-        let words = ((length + 63) / 64) as usize;
+        let bytes = ((length + 1) / 2) as usize;
         Self {
             length,
-            data: vec![0; words],
+            data: vec![0; bytes],
+        }
+    }
+
+    fn get(&self, idx: u64) -> u8 {
+        let byte = self.data[(idx / 2) as usize];
+        if idx % 2 == 0 { byte & 0x0F } else { byte >> 4 }
+    }
+
+    fn set_counter(&mut self, idx: u64, value: u8) {
+        let byte_idx = (idx / 2) as usize;
+        let value = value & COUNTER_MAX;
+        if idx % 2 == 0 {
+            self.data[byte_idx] = (self.data[byte_idx] & 0xF0) | value;
+        } else {
+            self.data[byte_idx] = (self.data[byte_idx] & 0x0F) | (value << 4);
+        }
+    }
+
+    /// Increments the counter at `idx`, saturating at `COUNTER_MAX` so a
+    /// slot shared by many colliding paths never wraps back around to zero.
+    fn increment(&mut self, idx: u64) {
+        if idx >= self.length {
+            return;
+        }
+        let current = self.get(idx);
+        if current < COUNTER_MAX {
+            self.set_counter(idx, current + 1);
         }
     }
 
-    fn set(&mut self, idx: u64) {
-        let word = (idx / 64) as usize;
-        let bit = idx % 64;
-        if word < self.data.len() {
-            self.data[word] |= 1u64 << bit;
+    /// Decrements the counter at `idx`. A zero counter is left alone rather
+    /// than underflowing, and a saturated counter is left alone too, since
+    /// saturation means its true count is already unknown.
+    fn decrement(&mut self, idx: u64) {
+        if idx >= self.length {
+            return;
+        }
+        let current = self.get(idx);
+        if current > 0 && current < COUNTER_MAX {
+            self.set_counter(idx, current - 1);
         }
     }
 
     fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
         writer.write_all(&self.length.to_be_bytes())?;
-        for &value in &self.data {
-            writer.write_all(&value.to_be_bytes())?;
-        }
+        writer.write_all(&[COUNTER_WIDTH_BITS])?;
+        writer.write_all(&self.data)?;
         Ok(())
     }
 
@@ -817,14 +1598,44 @@ impl BitSet {
         let mut len_buf = [0u8; 8];
         reader.read_exact(&mut len_buf)?;
         self.length = u64::from_be_bytes(len_buf);
-        let words = ((self.length + 63) / 64) as usize;
-        self.data = vec![0; words];
-        for i in 0..words {
+        let mut width_buf = [0u8; 1];
+        reader.read_exact(&mut width_buf)?;
+        if width_buf[0] != COUNTER_WIDTH_BITS {
+            bail!(
+                "unsupported bloom filter counter width: {}",
+                width_buf[0]
+            );
+        }
+        let bytes = ((self.length + 1) / 2) as usize;
+        self.data = vec![0; bytes];
+        reader.read_exact(&mut self.data)?;
+        Ok(())
+    }
+
+    /// Parses the pre-v8 plain bit-vector format (`length(8)` + `u64`
+    /// words) and promotes each set bit to a counter of 1, so upgrading an
+    /// older index preserves membership exactly.
+    fn from_legacy_bitset<R: Read>(mut reader: R) -> Result<Self> {
+        let mut len_buf = [0u8; 8];
+        reader.read_exact(&mut len_buf)?;
+        let length = u64::from_be_bytes(len_buf);
+        let words = ((length + 63) / 64) as usize;
+
+        let mut filter = Self::new(length);
+        for word_idx in 0..words {
             let mut buf = [0u8; 8];
             reader.read_exact(&mut buf)?;
-            self.data[i] = u64::from_be_bytes(buf);
+            let word = u64::from_be_bytes(buf);
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    let idx = (word_idx as u64) * 64 + bit as u64;
+                    if idx < filter.length {
+                        filter.set_counter(idx, 1);
+                    }
+                }
+            }
         }
-        Ok(())
+        Ok(filter)
     }
 }
 
@@ -854,13 +1665,32 @@ impl FnvHasher {
     }
 }
 
-fn base_hashes(data: &[u8]) -> [u64; 4] {
-    let mut hasher = Murmur3::new();
-    hasher.write(data);
-    let (v1, v2) = hasher.sum128();
-    hasher.write(&[1]);
-    let (v3, v4) = hasher.sum128();
-    [v1, v2, v3, v4]
+fn base_hashes(data: &[u8], backend: HashBackend) -> [u64; 4] {
+    match backend {
+        HashBackend::Murmur3 => {
+            let mut hasher = Murmur3::new();
+            hasher.write(data);
+            let (v1, v2) = hasher.sum128();
+            hasher.write(&[1]);
+            let (v3, v4) = hasher.sum128();
+            [v1, v2, v3, v4]
+        }
+        HashBackend::Xxh3 => {
+            let digest = xxh3_128(data);
+            let (v1, v2) = ((digest >> 64) as u64, digest as u64);
+
+            // Mirror the Murmur3 path's `hasher.write(&[1])` trick: hash the
+            // input again with a trailing sentinel byte to get a second,
+            // independent pair for `location()`'s double hashing.
+            let mut salted = Vec::with_capacity(data.len() + 1);
+            salted.extend_from_slice(data);
+            salted.push(1);
+            let digest2 = xxh3_128(&salted);
+            let (v3, v4) = ((digest2 >> 64) as u64, digest2 as u64);
+
+            [v1, v2, v3, v4]
+        }
+    }
 }
 
 fn location(h: &[u64; 4], i: u64) -> u64 {
@@ -1002,6 +1832,27 @@ fn fmix64(mut k: u64) -> u64 {
     k
 }
 
+/// Checks `path` against `ignore_patterns`, both by filename glob and by
+/// plain substring match against the full path. Used by the filesystem
+/// watcher, which has no live `FilesystemIndexer` to call a method on from
+/// its own thread; `should_skip` uses the precompiled `PatternMatcher`.
+pub(crate) fn should_skip_path(ignore_patterns: &[String], path: &Path) -> bool {
+    let base = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
+    for pattern in ignore_patterns {
+        if wildcard_match(pattern, base) {
+            return true;
+        }
+    }
+
+    let path_str = path.to_string_lossy();
+    for pattern in ignore_patterns {
+        if path_str.contains(pattern) {
+            return true;
+        }
+    }
+    false
+}
+
 fn wildcard_match(pattern: &str, value: &str) -> bool {
     if !pattern.contains('*') && !pattern.contains('?') {
         return pattern == value;
@@ -1033,6 +1884,271 @@ fn wildcard_match(pattern: &str, value: &str) -> bool {
     i == p.len()
 }
 
+/// A single node of the Aho-Corasick trie backing `PatternMatcher`: a
+/// byte-keyed transition table, a failure link (where to resume on a
+/// mismatch, computed by the BFS in `AhoCorasick::build`), and the ids of
+/// any needles that end exactly at this node.
+struct AcNode {
+    children: HashMap<u8, usize>,
+    fail: usize,
+    outputs: Vec<usize>,
+}
+
+/// Multi-pattern substring automaton: registers a set of byte needles once,
+/// then finds every occurrence of every needle in a text with a single
+/// linear pass, rather than scanning the text once per needle.
+struct AhoCorasick {
+    nodes: Vec<AcNode>,
+    needle_lens: Vec<usize>,
+}
+
+impl AhoCorasick {
+    fn build(needles: &[Vec<u8>]) -> Self {
+        let mut nodes = vec![AcNode {
+            children: HashMap::new(),
+            fail: 0,
+            outputs: Vec::new(),
+        }];
+        let mut needle_lens = Vec::with_capacity(needles.len());
+
+        for (id, needle) in needles.iter().enumerate() {
+            needle_lens.push(needle.len());
+            let mut cur = 0;
+            for &b in needle {
+                cur = *nodes[cur].children.entry(b).or_insert_with(|| {
+                    nodes.push(AcNode {
+                        children: HashMap::new(),
+                        fail: 0,
+                        outputs: Vec::new(),
+                    });
+                    nodes.len() - 1
+                });
+            }
+            nodes[cur].outputs.push(id);
+        }
+
+        // BFS over the trie to compute each node's failure link: the
+        // longest proper suffix of its path that is also a prefix of some
+        // needle. Root's direct children fail back to the root.
+        let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+        let root_children: Vec<usize> = nodes[0].children.values().copied().collect();
+        for child in root_children {
+            nodes[child].fail = 0;
+            queue.push_back(child);
+        }
+
+        while let Some(u) = queue.pop_front() {
+            let children: Vec<(u8, usize)> =
+                nodes[u].children.iter().map(|(&b, &n)| (b, n)).collect();
+            for (b, v) in children {
+                let mut f = nodes[u].fail;
+                while f != 0 && !nodes[f].children.contains_key(&b) {
+                    f = nodes[f].fail;
+                }
+                let candidate = nodes[f].children.get(&b).copied().unwrap_or(0);
+                nodes[v].fail = if candidate == v { 0 } else { candidate };
+                let inherited = nodes[nodes[v].fail].outputs.clone();
+                nodes[v].outputs.extend(inherited);
+                queue.push_back(v);
+            }
+        }
+
+        Self { nodes, needle_lens }
+    }
+
+    /// Runs `text` through the automaton once, returning every
+    /// `(needle_id, start_offset)` occurrence found.
+    fn scan(&self, text: &[u8]) -> Vec<(usize, usize)> {
+        let mut hits = Vec::new();
+        let mut state = 0usize;
+        for (i, &b) in text.iter().enumerate() {
+            loop {
+                if let Some(&next) = self.nodes[state].children.get(&b) {
+                    state = next;
+                    break;
+                } else if state == 0 {
+                    break;
+                } else {
+                    state = self.nodes[state].fail;
+                }
+            }
+            for &needle_id in &self.nodes[state].outputs {
+                let start = i + 1 - self.needle_lens[needle_id];
+                hits.push((needle_id, start));
+            }
+        }
+        hits
+    }
+}
+
+/// A needle registered in `PatternMatcher`'s shared automaton: either a
+/// pattern's raw text (for the plain substring check `should_skip_path`
+/// also runs) or one of a glob's `*`/`?`-delimited literal fragments.
+enum NeedleKind {
+    Raw(usize),
+    Fragment(usize),
+}
+
+/// One ignore pattern compiled for the Aho-Corasick prefilter: the needle
+/// ids (into the shared automaton) for its literal fragments in
+/// left-to-right order, plus the anchoring a leading/trailing `*` implies.
+struct CompiledGlob {
+    pattern: String,
+    fragment_needles: Vec<usize>,
+    anchored_start: bool,
+    anchored_end: bool,
+    literal: bool,
+}
+
+/// Accelerates `should_skip_path` for large ignore-pattern lists. Plain
+/// backtracking checks every pattern against every path, which is O(patterns
+/// x path length) once a config carries hundreds of globs. This instead
+/// compiles the pattern set once into a single Aho-Corasick automaton over
+/// each glob's literal fragments (split at `*`/`?`), then runs each path
+/// through it exactly once: only patterns whose fragments actually occur, in
+/// order, with the anchoring their leading/trailing `*` implies, are worth
+/// handing to the real backtracker in `wildcard_match`. Fully literal
+/// patterns (no `*`/`?`) resolve straight off the automaton hit, with no
+/// backtracking call at all.
+pub(crate) struct PatternMatcher {
+    globs: Vec<CompiledGlob>,
+    needle_kinds: Vec<NeedleKind>,
+    automaton: AhoCorasick,
+}
+
+impl PatternMatcher {
+    pub(crate) fn new(patterns: &[String]) -> Self {
+        let mut needles: Vec<Vec<u8>> = Vec::new();
+        let mut needle_kinds: Vec<NeedleKind> = Vec::new();
+        let mut globs = Vec::with_capacity(patterns.len());
+
+        for (glob_idx, pattern) in patterns.iter().enumerate() {
+            needles.push(pattern.as_bytes().to_vec());
+            needle_kinds.push(NeedleKind::Raw(glob_idx));
+
+            let literal = !pattern.contains('*') && !pattern.contains('?');
+            let fragment_needles = if literal {
+                Vec::new()
+            } else {
+                pattern
+                    .split(['*', '?'])
+                    .filter(|frag| !frag.is_empty())
+                    .map(|frag| {
+                        let id = needles.len();
+                        needles.push(frag.as_bytes().to_vec());
+                        needle_kinds.push(NeedleKind::Fragment(glob_idx));
+                        id
+                    })
+                    .collect()
+            };
+
+            globs.push(CompiledGlob {
+                pattern: pattern.clone(),
+                fragment_needles,
+                // A leading/trailing `*` or `?` leaves that end unanchored:
+                // the literal text doesn't begin (or end) exactly there, so
+                // anchoring on it would reject a real match; only an end
+                // with no wildcard is pinned.
+                anchored_start: !pattern.starts_with('*') && !pattern.starts_with('?'),
+                anchored_end: !pattern.ends_with('*') && !pattern.ends_with('?'),
+                literal,
+            });
+        }
+
+        Self {
+            globs,
+            needle_kinds,
+            automaton: AhoCorasick::build(&needles),
+        }
+    }
+
+    /// Mirrors `should_skip_path`'s two checks (basename glob, full-path
+    /// substring) but runs `path` through the shared automaton only once.
+    pub(crate) fn is_match(&self, path: &Path) -> bool {
+        let path_str = path.to_string_lossy();
+        let base = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let base_start = path_str.len().saturating_sub(base.len());
+
+        let mut raw_spans: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        let mut fragment_starts: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (needle_id, start) in self.automaton.scan(path_str.as_bytes()) {
+            let end = start + self.automaton.needle_lens[needle_id];
+            match self.needle_kinds[needle_id] {
+                NeedleKind::Raw(glob_idx) => {
+                    raw_spans.entry(glob_idx).or_default().push((start, end));
+                }
+                NeedleKind::Fragment(_) => {
+                    fragment_starts.entry(needle_id).or_default().push(start);
+                }
+            }
+        }
+
+        for (glob_idx, glob) in self.globs.iter().enumerate() {
+            // The plain substring pass `should_skip_path` runs against the
+            // full path, independent of any `*`/`?` in the pattern.
+            if raw_spans.contains_key(&glob_idx) {
+                return true;
+            }
+
+            if glob.literal {
+                continue;
+            }
+
+            if self.fragments_present_in_order(glob, &fragment_starts, base_start, path_str.len())
+                && wildcard_match(&glob.pattern, base)
+            {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Cheap, conservative feasibility check: can every fragment of `glob`
+    /// be found at a strictly increasing position within `[base_start,
+    /// path_len)`, honoring the anchoring its leading/trailing `*` implies?
+    /// Picking the leftmost eligible occurrence at each step is safe here —
+    /// if any valid placement exists, the leftmost-first greedy choice finds
+    /// one too — so a "no" here means `wildcard_match` could not possibly
+    /// succeed either.
+    fn fragments_present_in_order(
+        &self,
+        glob: &CompiledGlob,
+        fragment_starts: &HashMap<usize, Vec<usize>>,
+        base_start: usize,
+        path_len: usize,
+    ) -> bool {
+        let mut cursor = base_start;
+        let last = glob.fragment_needles.len().saturating_sub(1);
+        for (i, &needle_id) in glob.fragment_needles.iter().enumerate() {
+            let Some(starts) = fragment_starts.get(&needle_id) else {
+                return false;
+            };
+            let len = self.automaton.needle_lens[needle_id];
+
+            let next_cursor = if i == 0 && glob.anchored_start {
+                starts.contains(&cursor).then_some(cursor + len)
+            } else if i == last && glob.anchored_end {
+                starts
+                    .iter()
+                    .any(|&s| s >= cursor && s + len == path_len)
+                    .then_some(path_len)
+            } else {
+                starts.iter().filter(|&&s| s >= cursor).min().map(|s| s + len)
+            };
+
+            match next_cursor {
+                Some(next_cursor) => cursor = next_cursor,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
 fn file_flags(metadata: &fs::Metadata, path: &Path) -> u8 {
     let mut flags = 0;
     if metadata.is_dir() {
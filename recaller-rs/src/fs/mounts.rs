@@ -0,0 +1,92 @@
+use std::fs;
+
+/// A single mounted filesystem and its live usage, as reported by the OS.
+#[derive(Debug, Clone)]
+pub struct MountInfo {
+    pub device: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub free_bytes: u64,
+}
+
+impl MountInfo {
+    pub fn used_percent(&self) -> f64 {
+        if self.total_bytes == 0 {
+            0.0
+        } else {
+            (self.used_bytes as f64 / self.total_bytes as f64) * 100.0
+        }
+    }
+}
+
+/// Enumerates mounted filesystems on the current platform. Linux is read
+/// from `/proc/mounts` + `statvfs`; other platforms return an empty list
+/// until a native enumerator is wired in.
+pub fn list_mounts() -> Vec<MountInfo> {
+    #[cfg(target_os = "linux")]
+    {
+        list_mounts_linux()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn list_mounts_linux() -> Vec<MountInfo> {
+    let contents = match fs::read_to_string("/proc/mounts") {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut mounts = Vec::new();
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 3 {
+            continue;
+        }
+        let device = fields[0].to_string();
+        let mount_point = fields[1].to_string();
+        let fs_type = fields[2].to_string();
+
+        if !device.starts_with('/') {
+            continue;
+        }
+
+        let (total_bytes, free_bytes) = statvfs_bytes(&mount_point).unwrap_or((0, 0));
+        let used_bytes = total_bytes.saturating_sub(free_bytes);
+
+        mounts.push(MountInfo {
+            device,
+            mount_point,
+            fs_type,
+            total_bytes,
+            used_bytes,
+            free_bytes,
+        });
+    }
+
+    mounts
+}
+
+#[cfg(target_os = "linux")]
+fn statvfs_bytes(path: &str) -> Option<(u64, u64)> {
+    use std::ffi::CString;
+    use std::mem::MaybeUninit;
+
+    let cpath = CString::new(path).ok()?;
+    let mut stat = MaybeUninit::<libc::statvfs>::uninit();
+    let rc = unsafe { libc::statvfs(cpath.as_ptr(), stat.as_mut_ptr()) };
+    if rc != 0 {
+        return None;
+    }
+    let stat = unsafe { stat.assume_init() };
+    let block_size = stat.f_frsize as u64;
+    let total = stat.f_blocks as u64 * block_size;
+    let free = stat.f_bavail as u64 * block_size;
+    Some((total, free))
+}
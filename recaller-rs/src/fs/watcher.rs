@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+
+use super::indexer::should_skip_path;
+
+/// One filesystem change translated from a raw `notify` event, already
+/// coalesced with any other events for the same path inside the debounce
+/// window.
+#[derive(Debug, Clone)]
+pub enum WatchEvent {
+    Upsert(String),
+    Remove(String),
+}
+
+/// How long to wait after the last event touching a path before flushing the
+/// coalesced batch. Editors and build tools fire bursts of create/modify
+/// events for a single logical save, and `notify` itself often splits one
+/// rename into two events; this keeps that burst down to one settled change
+/// instead of several redundant index updates.
+const DEBOUNCE_WINDOW: Duration = Duration::from_millis(400);
+
+/// Watches every path in `roots` recursively and sends coalesced batches of
+/// `WatchEvent`s to `on_batch` as they settle. The caller owns the returned
+/// watcher and must keep it alive for as long as watching should continue —
+/// dropping it stops the watch and tears down the debounce thread once the
+/// channel disconnects.
+pub fn spawn(
+    roots: &[String],
+    ignore_patterns: Vec<String>,
+    on_batch: Sender<Vec<WatchEvent>>,
+) -> Result<RecommendedWatcher> {
+    let (raw_tx, raw_rx) = mpsc::channel::<Event>();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            let _ = raw_tx.send(event);
+        }
+    })
+    .context("failed to start filesystem watcher")?;
+
+    let mut watched_any = false;
+    for root in roots {
+        match watcher.watch(Path::new(root), RecursiveMode::Recursive) {
+            Ok(()) => watched_any = true,
+            Err(err) => eprintln!("⚠️  Failed to watch {root}: {err}"),
+        }
+    }
+    if !watched_any {
+        bail!("no watchable root paths");
+    }
+
+    thread::spawn(move || debounce_loop(raw_rx, ignore_patterns, on_batch));
+
+    Ok(watcher)
+}
+
+/// Accumulates raw `notify` events into a per-path map of the most recent
+/// `WatchEvent`, flushing whenever `DEBOUNCE_WINDOW` passes with no new
+/// events — a trailing-edge debounce, the same idea as a cache-with-events
+/// design coalescing a burst of invalidations into one.
+fn debounce_loop(
+    raw_rx: mpsc::Receiver<Event>,
+    ignore_patterns: Vec<String>,
+    on_batch: Sender<Vec<WatchEvent>>,
+) {
+    let mut pending: HashMap<String, WatchEvent> = HashMap::new();
+
+    loop {
+        match raw_rx.recv_timeout(DEBOUNCE_WINDOW) {
+            Ok(event) => apply_event(&mut pending, &ignore_patterns, event),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if pending.is_empty() {
+                    continue;
+                }
+                let batch = pending.drain().map(|(_, event)| event).collect();
+                if on_batch.send(batch).is_err() {
+                    return;
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Folds one raw `notify` event into `pending`, treating a rename as a
+/// delete of the old path plus an add of the new one. `notify` reports a
+/// same-watch rename either as a single event with both paths (`Both`) or as
+/// a separate `From`/`To` pair depending on platform, so both shapes are
+/// handled.
+fn apply_event(pending: &mut HashMap<String, WatchEvent>, ignore_patterns: &[String], event: Event) {
+    match event.kind {
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+            record(pending, ignore_patterns, &event.paths[0], false);
+            record(pending, ignore_patterns, &event.paths[1], true);
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            for path in &event.paths {
+                record(pending, ignore_patterns, path, false);
+            }
+        }
+        EventKind::Remove(_) => {
+            for path in &event.paths {
+                record(pending, ignore_patterns, path, false);
+            }
+        }
+        EventKind::Create(_) | EventKind::Modify(_) => {
+            for path in &event.paths {
+                record(pending, ignore_patterns, path, true);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn record(pending: &mut HashMap<String, WatchEvent>, ignore_patterns: &[String], path: &Path, upsert: bool) {
+    if should_skip_path(ignore_patterns, path) {
+        return;
+    }
+    let path_str = path.to_string_lossy().to_string();
+    let event = if upsert {
+        WatchEvent::Upsert(path_str.clone())
+    } else {
+        WatchEvent::Remove(path_str.clone())
+    };
+    pending.insert(path_str, event);
+}
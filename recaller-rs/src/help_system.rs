@@ -0,0 +1,10 @@
+pub mod ansi;
+pub mod cache;
+pub mod disk_cache;
+pub mod manager;
+pub mod markdown;
+pub mod parser;
+pub mod runner;
+pub mod snippet;
+pub mod strategies;
+pub mod strategy;
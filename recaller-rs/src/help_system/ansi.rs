@@ -0,0 +1,162 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Parses text containing ANSI CSI SGR escape sequences (the color/bold/
+/// underline codes tools like `docker --help` emit) into styled `ratatui`
+/// `Line`s, one per `\n`-separated row. Style state carries across lines
+/// until it is explicitly reset, matching real terminal behavior.
+pub fn parse_lines(text: &str) -> Vec<Line<'static>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut lines = Vec::new();
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut buf = String::new();
+    let mut style = Style::default();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\u{1b}' && chars.get(i + 1) == Some(&'[') {
+            let mut j = i + 2;
+            while j < chars.len() && !chars[j].is_ascii_alphabetic() {
+                j += 1;
+            }
+            if j >= chars.len() {
+                break;
+            }
+            let terminator = chars[j];
+            if terminator == 'm' {
+                if !buf.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut buf), style));
+                }
+                let params: String = chars[i + 2..j].iter().collect();
+                style = apply_sgr(style, &params);
+            }
+            i = j + 1;
+            continue;
+        }
+
+        if c == '\n' {
+            if !buf.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut buf), style));
+            }
+            lines.push(Line::from(std::mem::take(&mut spans)));
+            i += 1;
+            continue;
+        }
+
+        buf.push(c);
+        i += 1;
+    }
+
+    if !buf.is_empty() {
+        spans.push(Span::styled(buf, style));
+    }
+    if !spans.is_empty() {
+        lines.push(Line::from(spans));
+    }
+
+    lines
+}
+
+/// Strips CSI escape sequences entirely, for contexts (search matching,
+/// length calculations) that need plain text.
+pub fn strip_ansi(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\u{1b}' && chars.get(i + 1) == Some(&'[') {
+            let mut j = i + 2;
+            while j < chars.len() && !chars[j].is_ascii_alphabetic() {
+                j += 1;
+            }
+            i = if j < chars.len() { j + 1 } else { chars.len() };
+            continue;
+        }
+        out.push(c);
+        i += 1;
+    }
+    out
+}
+
+fn apply_sgr(style: Style, params: &str) -> Style {
+    let mut style = style;
+    let codes: Vec<i64> = if params.is_empty() {
+        vec![0]
+    } else {
+        params
+            .split(';')
+            .map(|p| p.parse::<i64>().unwrap_or(0))
+            .collect()
+    };
+
+    let mut iter = codes.into_iter();
+    while let Some(code) = iter.next() {
+        match code {
+            0 => style = Style::default(),
+            1 => style = style.add_modifier(Modifier::BOLD),
+            4 => style = style.add_modifier(Modifier::UNDERLINED),
+            22 => style = style.remove_modifier(Modifier::BOLD),
+            24 => style = style.remove_modifier(Modifier::UNDERLINED),
+            30..=37 => style = style.fg(basic_color((code - 30) as u8, false)),
+            38 => match iter.next() {
+                Some(5) => {
+                    if let Some(n) = iter.next() {
+                        style = style.fg(Color::Indexed(n as u8));
+                    }
+                }
+                Some(2) => {
+                    let r = iter.next().unwrap_or(0) as u8;
+                    let g = iter.next().unwrap_or(0) as u8;
+                    let b = iter.next().unwrap_or(0) as u8;
+                    style = style.fg(Color::Rgb(r, g, b));
+                }
+                _ => {}
+            },
+            39 => style = style.fg(Color::Reset),
+            40..=47 => style = style.bg(basic_color((code - 40) as u8, false)),
+            48 => match iter.next() {
+                Some(5) => {
+                    if let Some(n) = iter.next() {
+                        style = style.bg(Color::Indexed(n as u8));
+                    }
+                }
+                Some(2) => {
+                    let r = iter.next().unwrap_or(0) as u8;
+                    let g = iter.next().unwrap_or(0) as u8;
+                    let b = iter.next().unwrap_or(0) as u8;
+                    style = style.bg(Color::Rgb(r, g, b));
+                }
+                _ => {}
+            },
+            49 => style = style.bg(Color::Reset),
+            90..=97 => style = style.fg(basic_color((code - 90) as u8, true)),
+            100..=107 => style = style.bg(basic_color((code - 100) as u8, true)),
+            _ => {}
+        }
+    }
+    style
+}
+
+fn basic_color(idx: u8, bright: bool) -> Color {
+    match (idx, bright) {
+        (0, false) => Color::Black,
+        (1, false) => Color::Red,
+        (2, false) => Color::Green,
+        (3, false) => Color::Yellow,
+        (4, false) => Color::Blue,
+        (5, false) => Color::Magenta,
+        (6, false) => Color::Cyan,
+        (7, false) => Color::Gray,
+        (0, true) => Color::DarkGray,
+        (1, true) => Color::LightRed,
+        (2, true) => Color::LightGreen,
+        (3, true) => Color::LightYellow,
+        (4, true) => Color::LightBlue,
+        (5, true) => Color::LightMagenta,
+        (6, true) => Color::LightCyan,
+        (7, true) => Color::White,
+        _ => Color::Reset,
+    }
+}
@@ -1,36 +1,80 @@
-use std::time::{Duration, Instant};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use dashmap::DashMap;
+use directories::BaseDirs;
+use serde::{Deserialize, Serialize};
 
 const HELP_CACHE_EXPIRATION: Duration = Duration::from_secs(30 * 60);
+const DEFAULT_MAX_ENTRIES: usize = 500;
 
+#[derive(Clone, Serialize, Deserialize)]
 struct CacheEntry {
     value: String,
-    expires_at: Instant,
+    expires_at: u64,
 }
 
 impl CacheEntry {
-    fn new(value: String) -> Self {
+    fn new(value: String, ttl: Duration) -> Self {
         Self {
             value,
-            expires_at: Instant::now() + HELP_CACHE_EXPIRATION,
+            expires_at: now_secs() + ttl.as_secs(),
         }
     }
 
     fn is_expired(&self) -> bool {
-        Instant::now() >= self.expires_at
+        now_secs() >= self.expires_at
     }
 }
 
-#[derive(Default)]
+/// Resolved-help cache shared by every `HelpStrategy`. Entries persist to a
+/// JSON file under the user's cache directory so a second run of the same
+/// command skips re-running slow `--help` subprocesses, and are capped at
+/// `max_entries` (oldest insertion evicted first) so the file can't grow
+/// unbounded.
 pub struct HelpCache {
     entries: DashMap<String, CacheEntry>,
+    order: Mutex<VecDeque<String>>,
+    ttl: Duration,
+    max_entries: usize,
+    disk_path: Option<PathBuf>,
 }
 
 impl HelpCache {
+    /// Builds a cache with the default 30-minute TTL and entry cap.
     pub fn new() -> Self {
+        Self::with_options(HELP_CACHE_EXPIRATION, DEFAULT_MAX_ENTRIES)
+    }
+
+    /// Builds a cache with a configurable TTL and entry cap, loading any
+    /// previously persisted entries from disk.
+    pub fn with_options(ttl: Duration, max_entries: usize) -> Self {
+        let disk_path = disk_cache_path();
+        let entries = DashMap::new();
+        let mut order = VecDeque::new();
+
+        if let Some(path) = &disk_path {
+            if let Ok(data) = fs::read_to_string(path) {
+                if let Ok(loaded) = serde_json::from_str::<Vec<(String, CacheEntry)>>(&data) {
+                    for (key, entry) in loaded {
+                        if !entry.is_expired() {
+                            order.push_back(key.clone());
+                            entries.insert(key, entry);
+                        }
+                    }
+                }
+            }
+        }
+
         Self {
-            entries: DashMap::new(),
+            entries,
+            order: Mutex::new(order),
+            ttl,
+            max_entries,
+            disk_path,
         }
     }
 
@@ -47,7 +91,62 @@ impl HelpCache {
     }
 
     pub fn insert(&self, key: impl Into<String>, value: impl Into<String>) {
-        let entry = CacheEntry::new(value.into());
-        self.entries.insert(key.into(), entry);
+        let key = key.into();
+        let entry = CacheEntry::new(value.into(), self.ttl);
+
+        if self.entries.insert(key.clone(), entry).is_none() {
+            let mut order = self.order.lock().unwrap();
+            order.push_back(key);
+            while order.len() > self.max_entries {
+                if let Some(oldest) = order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+
+        self.flush();
     }
+
+    fn flush(&self) {
+        let Some(path) = &self.disk_path else {
+            return;
+        };
+
+        let order = self.order.lock().unwrap();
+        let snapshot: Vec<(String, CacheEntry)> = order
+            .iter()
+            .filter_map(|key| self.entries.get(key).map(|e| (key.clone(), e.clone())))
+            .collect();
+        drop(order);
+
+        if let Ok(json) = serde_json::to_string(&snapshot) {
+            let _ = fs::write(path, json);
+        }
+    }
+}
+
+impl Default for HelpCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for HelpCache {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn disk_cache_path() -> Option<PathBuf> {
+    let base = BaseDirs::new()?;
+    let dir = base.cache_dir().join("recaller");
+    fs::create_dir_all(&dir).ok()?;
+    Some(dir.join("help_cache.json"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
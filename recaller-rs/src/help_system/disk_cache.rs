@@ -0,0 +1,97 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use directories::BaseDirs;
+
+/// A small on-disk cache for help bodies fetched over the network (TLDR, cheat.sh),
+/// keyed by the full command path. Each entry is stored as `<fetched_at_secs>\n<body>`.
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl_hours: u32,
+}
+
+impl DiskCache {
+    pub fn new(namespace: &str, ttl_hours: u32) -> Result<Self> {
+        let base = BaseDirs::new().context("failed to determine cache directory")?;
+        let dir = base.cache_dir().join("recaller").join(namespace);
+        fs::create_dir_all(&dir).context("failed to create help cache directory")?;
+        Ok(Self { dir, ttl_hours })
+    }
+
+    /// Returns the cached body if present and still within the freshness window.
+    pub fn get_fresh(&self, key: &str) -> Option<String> {
+        let (fetched_at, body) = self.read_entry(key)?;
+        if now_secs().saturating_sub(fetched_at) < self.ttl_hours as u64 * 3600 {
+            Some(body)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cached body regardless of age, for offline fallback.
+    pub fn get_stale(&self, key: &str) -> Option<String> {
+        self.read_entry(key).map(|(_, body)| body)
+    }
+
+    pub fn store(&self, key: &str, body: &str) {
+        let contents = format!("{}\n{}", now_secs(), body);
+        let _ = fs::write(self.entry_path(key), contents);
+    }
+
+    /// Removes every entry older than `ttl_hours`, regardless of what key
+    /// it was stored under. Used by `recaller settings clean-help-cache` so
+    /// stale entries don't linger forever even if their command is never
+    /// looked up again.
+    pub fn purge_stale(&self) -> Result<usize> {
+        let mut removed = 0;
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(0),
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(contents) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let Some((header, _)) = contents.split_once('\n') else {
+                continue;
+            };
+            let Ok(fetched_at) = header.trim().parse::<u64>() else {
+                continue;
+            };
+            if now_secs().saturating_sub(fetched_at) >= self.ttl_hours as u64 * 3600 {
+                let _ = fs::remove_file(&path);
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn read_entry(&self, key: &str) -> Option<(u64, String)> {
+        let contents = fs::read_to_string(self.entry_path(key)).ok()?;
+        let (header, body) = contents.split_once('\n')?;
+        let fetched_at = header.trim().parse::<u64>().ok()?;
+        Some((fetched_at, body.to_string()))
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.cache", hash_key(key)))
+    }
+}
+
+fn hash_key(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
@@ -1,25 +1,30 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::{Result, anyhow};
 
+use crate::config::HelpConfig;
 use crate::help_system::cache::HelpCache;
 use crate::help_system::runner::CommandRunner;
 use crate::help_system::strategies::{
-    AwsHelpStrategy, CargoHelpStrategy, DockerHelpStrategy, GenericHelpStrategy, GitHelpStrategy,
-    GoHelpStrategy, KubectlHelpStrategy, ManPageStrategy, NpmHelpStrategy, TldrStrategy,
+    AwsHelpStrategy, CargoHelpStrategy, CheatShStrategy, CheatsHelpStrategy, DockerHelpStrategy,
+    GenericHelpStrategy, GitHelpStrategy, GoHelpStrategy, KubectlHelpStrategy, ManPageStrategy,
+    NpmHelpStrategy, TemplatedHelpStrategy, TldrStrategy,
 };
 use crate::help_system::strategy::{CommandParts, HelpStrategy};
 
 pub struct HelpManager {
     cache: HelpCache,
-    strategies: Vec<Box<dyn HelpStrategy>>, // excluding TLDR
-    tldr_strategy: TldrStrategy,
+    strategies: Vec<Box<dyn HelpStrategy>>,
 }
 
 impl HelpManager {
-    pub fn new() -> Self {
-        let runner = Arc::new(CommandRunner::new());
+    pub fn new(help_config: &HelpConfig) -> Self {
+        let runner = Arc::new(CommandRunner::with_cache(help_config.cache_duration_hours));
         let mut strategies: Vec<Box<dyn HelpStrategy>> = Vec::new();
+        // Tried first, above every generated strategy below: a user's own
+        // curated snippet beats vendor help, man pages, and tldr/cheat.sh.
+        strategies.push(Box::new(CheatsHelpStrategy::new()));
         strategies.push(Box::new(GitHelpStrategy::new(runner.clone())));
         strategies.push(Box::new(GoHelpStrategy::new(runner.clone())));
         strategies.push(Box::new(KubectlHelpStrategy::new(runner.clone())));
@@ -28,17 +33,43 @@ impl HelpManager {
         strategies.push(Box::new(AwsHelpStrategy::new(runner.clone())));
         strategies.push(Box::new(DockerHelpStrategy::new(runner.clone())));
         strategies.push(Box::new(ManPageStrategy::new(runner.clone())));
+        // Example-first fallback for commands whose man page is terse or
+        // missing: tried right after man, before any flag-probing or
+        // network strategy gets a chance.
+        strategies.push(Box::new(TldrStrategy::new(
+            runner.clone(),
+            help_config.tldr_repo_path.clone(),
+        )));
+        for entry in &help_config.custom_commands {
+            strategies.push(Box::new(TemplatedHelpStrategy::new(
+                runner.clone(),
+                entry.clone(),
+            )));
+        }
+        // Community cheatsheet fallback: a network round trip, so it's only
+        // tried once every local strategy, including Generic, has declined.
+        strategies.push(Box::new(CheatShStrategy::new(
+            runner.clone(),
+            help_config.cache_duration_hours,
+            help_config.online,
+        )));
         strategies.push(Box::new(GenericHelpStrategy::new(runner)));
 
         strategies.sort_by_key(|s| s.priority());
 
+        let cache_ttl = Duration::from_secs(help_config.cache_duration_hours as u64 * 3600);
         Self {
-            cache: HelpCache::new(),
+            cache: HelpCache::with_options(cache_ttl, help_config.cache_max_entries),
             strategies,
-            tldr_strategy: TldrStrategy::new(),
         }
     }
 
+    /// Evicts expired entries from the on-disk help cache without spinning
+    /// up the full set of strategies. Backs `recaller settings clean-help-cache`.
+    pub fn purge_stale_cache(help_config: &HelpConfig) -> Result<usize> {
+        CommandRunner::with_cache(help_config.cache_duration_hours).purge_help_cache()
+    }
+
     pub fn get_help(&self, command: &[String]) -> Result<String> {
         if command.is_empty() {
             return Err(anyhow!("no command provided"));
@@ -52,13 +83,6 @@ impl HelpManager {
         let parts = CommandParts::new(command.to_vec());
         let mut last_err = None;
 
-        if let Ok(help) = self.tldr_strategy.get_help(&parts) {
-            if !help.trim().is_empty() {
-                self.cache.insert(&command_key, &help);
-                return Ok(help);
-            }
-        }
-
         let base_cmd = parts.base_cmd().unwrap_or("");
         for strategy in self
             .strategies
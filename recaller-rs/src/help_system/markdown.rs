@@ -0,0 +1,179 @@
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+/// Lightweight CommonMark-ish renderer for help output (tldr pages,
+/// markdown-formatted `--help` text) that helix's `ui/markdown.rs` inspired:
+/// `#`/`##` headings become bold colored lines, `**bold**`/`` `code` ``
+/// become styled spans, and fenced (```` ``` ````) or 4-space-indented code
+/// blocks get a distinct background. Anything else passes through as plain
+/// text, so calling this on non-markdown content is harmless.
+pub fn parse_markdown(text: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_fence = false;
+
+    for raw in text.lines() {
+        if let Some(_lang) = raw.trim_start().strip_prefix("```") {
+            in_fence = !in_fence;
+            lines.push(Line::styled(String::new(), code_block_style()));
+            continue;
+        }
+
+        if in_fence {
+            lines.push(Line::styled(raw.to_string(), code_block_style()));
+            continue;
+        }
+
+        if let Some(indented) = raw.strip_prefix("    ").or_else(|| raw.strip_prefix('\t')) {
+            lines.push(Line::styled(indented.to_string(), code_block_style()));
+            continue;
+        }
+
+        if let Some(heading) = heading_line(raw) {
+            lines.push(heading);
+            continue;
+        }
+
+        lines.push(Line::from(parse_inline(raw)));
+    }
+
+    lines
+}
+
+/// Heuristic: does `text` look enough like markdown to justify the styled
+/// renderer, or should the caller fall back to plain/ANSI rendering? Looks
+/// for the constructs `parse_markdown` actually understands rather than
+/// trying to be a full markdown sniffer.
+pub fn looks_like_markdown(text: &str) -> bool {
+    let mut fences = 0;
+    let mut hits = 0;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            fences += 1;
+            continue;
+        }
+        if trimmed.starts_with('#') && trimmed.trim_start_matches('#').starts_with(' ') {
+            hits += 1;
+        }
+        if has_inline_markup(line) {
+            hits += 1;
+        }
+    }
+
+    fences >= 2 || hits >= 2
+}
+
+fn heading_line(raw: &str) -> Option<Line<'static>> {
+    let trimmed = raw.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = trimmed[level..].strip_prefix(' ')?;
+    let color = if level == 1 { Color::Magenta } else { Color::Cyan };
+    Some(Line::styled(
+        rest.to_string(),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn has_inline_markup(line: &str) -> bool {
+    find_delim(line, "**").is_some()
+        || find_delim(line, "`").is_some()
+        || find_bracket(line, "{{", "}}").is_some()
+}
+
+#[derive(Clone, Copy)]
+enum InlineKind {
+    Bold,
+    Code,
+    Placeholder,
+}
+
+/// Splits a single line into spans, styling `**bold**`, `` `code` `` runs,
+/// and `{{placeholder}}` tokens (tldr-page convention for the bit a reader
+/// is meant to fill in before running the example), leaving everything else
+/// as plain spans.
+fn parse_inline(line: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut rest = line;
+
+    loop {
+        let bold = find_delim(rest, "**").map(|m| (m, InlineKind::Bold));
+        let code = find_delim(rest, "`").map(|m| (m, InlineKind::Code));
+        let placeholder = find_bracket(rest, "{{", "}}").map(|m| (m, InlineKind::Placeholder));
+
+        let next = [bold, code, placeholder]
+            .into_iter()
+            .flatten()
+            .min_by_key(|(m, _)| m.0);
+
+        let Some(((start, end, inner_start, inner_end), kind)) = next else {
+            if !rest.is_empty() {
+                spans.push(Span::raw(rest.to_string()));
+            }
+            break;
+        };
+
+        if start > 0 {
+            spans.push(Span::raw(rest[..start].to_string()));
+        }
+        let (style, text) = match kind {
+            InlineKind::Bold => (
+                Style::default().add_modifier(Modifier::BOLD),
+                rest[inner_start..inner_end].to_string(),
+            ),
+            InlineKind::Code => (code_span_style(), rest[inner_start..inner_end].to_string()),
+            InlineKind::Placeholder => (placeholder_style(), rest[start..end].to_string()),
+        };
+        spans.push(Span::styled(text, style));
+        rest = &rest[end..];
+    }
+
+    if spans.is_empty() {
+        spans.push(Span::raw(String::new()));
+    }
+    spans
+}
+
+/// Finds the next `delim ... delim` pair in `text`, returning
+/// `(start, end, inner_start, inner_end)` byte offsets, where `start..end`
+/// spans the whole delimited run and `inner_start..inner_end` is the content
+/// between the delimiters.
+fn find_delim(text: &str, delim: &str) -> Option<(usize, usize, usize, usize)> {
+    let open = text.find(delim)?;
+    let inner_start = open + delim.len();
+    let close_rel = text[inner_start..].find(delim)?;
+    let inner_end = inner_start + close_rel;
+    if inner_end == inner_start {
+        return None;
+    }
+    let end = inner_end + delim.len();
+    Some((open, end, inner_start, inner_end))
+}
+
+/// Like `find_delim`, but for an asymmetric `open ... close` pair such as
+/// tldr's `{{placeholder}}` tokens.
+fn find_bracket(text: &str, open: &str, close: &str) -> Option<(usize, usize, usize, usize)> {
+    let start = text.find(open)?;
+    let inner_start = start + open.len();
+    let close_rel = text[inner_start..].find(close)?;
+    let inner_end = inner_start + close_rel;
+    let end = inner_end + close.len();
+    Some((start, end, inner_start, inner_end))
+}
+
+fn code_block_style() -> Style {
+    Style::default().bg(Color::Rgb(40, 40, 40)).fg(Color::Gray)
+}
+
+fn code_span_style() -> Style {
+    Style::default().bg(Color::Rgb(40, 40, 40)).fg(Color::LightYellow)
+}
+
+fn placeholder_style() -> Style {
+    Style::default()
+        .fg(Color::LightCyan)
+        .add_modifier(Modifier::ITALIC)
+}
@@ -1,27 +1,101 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::io::Read;
 use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Sender, SyncSender};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::{Context, Result, anyhow};
 use wait_timeout::ChildExt;
 
+use crate::help_system::disk_cache::DiskCache;
+use crate::process::format_command_error;
+
 const DEFAULT_CMD_TIMEOUT: Duration = Duration::from_secs(30);
 const GIT_CMD_TIMEOUT: Duration = Duration::from_secs(15);
 const MAX_OUTPUT_SIZE: usize = 1024 * 1024; // 1MB
+/// How long `run_streaming` accumulates output before it starts forwarding
+/// chunks, so commands that finish quickly still produce a single clean
+/// `StreamChunk::Data` instead of a burst of tiny ones.
+const STREAM_GRACE_WINDOW: Duration = Duration::from_millis(100);
+
+/// A piece of output delivered by `run_streaming`, in arrival order.
+#[allow(dead_code)]
+pub enum StreamChunk {
+    /// A slice of stdout bytes.
+    Data(Vec<u8>),
+    /// The command has finished (or was killed for timing out); `truncated`
+    /// mirrors `LimitedBuffer`'s semantics and is set once `MAX_OUTPUT_SIZE`
+    /// is reached or the command had to be killed.
+    Done { truncated: bool },
+}
 
-pub struct CommandRunner;
+pub struct CommandRunner {
+    /// Backing store for `run_cached`. `None` when constructed via `new`,
+    /// which makes `run_cached` behave just like `run` — callers that don't
+    /// go through `with_cache` still get correct (if unaccelerated) output
+    /// rather than an error.
+    cache: Option<DiskCache>,
+}
 
 impl CommandRunner {
     pub fn new() -> Self {
-        Self
+        Self { cache: None }
+    }
+
+    /// Builds a runner whose `run_cached` calls are backed by an on-disk
+    /// cache under `~/.cache/recaller/help`, so repeated documentation
+    /// lookups for the same slow CLI (`aws s3api help` and the like) read
+    /// from disk instead of re-spawning the process.
+    pub fn with_cache(cache_duration_hours: u32) -> Self {
+        Self {
+            cache: DiskCache::new("help", cache_duration_hours).ok(),
+        }
     }
 
     pub fn run(&self, program: &str, args: &[&str]) -> Result<String> {
         self.run_with_timeout(DEFAULT_CMD_TIMEOUT, program, args, &[])
     }
 
+    /// Like `run_with_env`, but checks the on-disk help cache first (keyed
+    /// by a hash of `program`, `args` and `env`) and stores a successful
+    /// result back into it. Meant for help text — expensive to produce but
+    /// stable between calls — not for git or other interactive/stateful
+    /// commands, which should keep using `run`/`run_git`.
+    pub fn run_cached(&self, program: &str, args: &[&str], env: &[(&str, &str)]) -> Result<String> {
+        let key = command_cache_key(program, args, env);
+        if let Some(cache) = &self.cache {
+            if let Some(output) = cache.get_fresh(&key) {
+                return Ok(output);
+            }
+        }
+
+        let output = self.run_with_timeout(DEFAULT_CMD_TIMEOUT, program, args, env)?;
+        if let Some(cache) = &self.cache {
+            cache.store(&key, &output);
+        }
+        Ok(output)
+    }
+
+    /// Evicts help cache entries past their TTL; a no-op if this runner was
+    /// built via `new` rather than `with_cache`. Backs `recaller settings
+    /// clean-help-cache`.
+    pub fn purge_help_cache(&self) -> Result<usize> {
+        match &self.cache {
+            Some(cache) => cache.purge_stale(),
+            None => Ok(0),
+        }
+    }
+
     pub fn run_git(&self, program: &str, args: &[&str]) -> Result<String> {
+        self.run_short(program, args)
+    }
+
+    /// Runs a command expected to answer quickly, like `run_git` but not
+    /// specific to git — used for anything else that shouldn't wait the
+    /// full `DEFAULT_CMD_TIMEOUT` (e.g. `curl`/`wget` hitting cheat.sh).
+    pub fn run_short(&self, program: &str, args: &[&str]) -> Result<String> {
         self.run_with_timeout(GIT_CMD_TIMEOUT, program, args, &[])
     }
 
@@ -38,6 +112,142 @@ impl CommandRunner {
         which::which(program).is_ok()
     }
 
+    /// Like `run`, but delivers stdout to `sink` as it arrives instead of
+    /// buffering the whole thing, so a long man page can render while it's
+    /// still printing rather than only once the process exits.
+    ///
+    /// Starts in a short buffering window (`STREAM_GRACE_WINDOW`) so fast
+    /// commands still arrive as one clean `StreamChunk::Data`; once the
+    /// window elapses without EOF, chunks are forwarded as soon as they're
+    /// read. Ends with exactly one `StreamChunk::Done` unless it returns an
+    /// error (e.g. the program fails to spawn). This call blocks until the
+    /// command exits or times out, so callers that want the UI to stay
+    /// responsive should run it on a background thread. `sink` is a bounded
+    /// `SyncSender` (from `mpsc::sync_channel`), so a consumer that falls
+    /// behind blocks forwarding instead of letting chunks pile up.
+    ///
+    /// Not yet wired into the TUI help pane, which still calls `get_help`
+    /// synchronously and renders the result in one shot; kept available for
+    /// that follow-up rather than deleted mid-flight.
+    #[allow(dead_code)]
+    pub fn run_streaming(
+        &self,
+        program: &str,
+        args: &[&str],
+        env: &[(&str, &str)],
+        sink: SyncSender<StreamChunk>,
+    ) -> Result<()> {
+        let timeout = DEFAULT_CMD_TIMEOUT;
+        let mut cmd = Command::new(program);
+        cmd.args(args);
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+        for (key, value) in env {
+            cmd.env(key, value);
+        }
+
+        let mut child = cmd
+            .spawn()
+            .with_context(|| format!("failed to spawn {program}"))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("failed to capture stdout"))?;
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| anyhow!("failed to capture stderr"))?;
+
+        let stderr_buffer = Arc::new(Mutex::new(LimitedBuffer::new(MAX_OUTPUT_SIZE)));
+        let stderr_buffer_clone = stderr_buffer.clone();
+        let stderr_handle = std::thread::spawn(move || read_stream(stderr, stderr_buffer_clone));
+
+        let (raw_tx, raw_rx) = mpsc::channel::<Vec<u8>>();
+        let stdout_handle =
+            std::thread::spawn(move || read_stream_chunks(stdout, raw_tx, MAX_OUTPUT_SIZE));
+
+        let start = Instant::now();
+        let grace_deadline = start + STREAM_GRACE_WINDOW;
+        let mut pending = Vec::new();
+        let mut streaming = false;
+        let mut timed_out = false;
+
+        loop {
+            let now = Instant::now();
+            if now.duration_since(start) >= timeout {
+                timed_out = true;
+                break;
+            }
+            if !streaming && now >= grace_deadline {
+                streaming = true;
+                if !pending.is_empty() {
+                    let _ = sink.send(StreamChunk::Data(std::mem::take(&mut pending)));
+                }
+            }
+            let wait = if streaming {
+                timeout.saturating_sub(now.duration_since(start))
+            } else {
+                grace_deadline.saturating_duration_since(now)
+            };
+            match raw_rx.recv_timeout(wait) {
+                Ok(data) => {
+                    if streaming {
+                        let _ = sink.send(StreamChunk::Data(data));
+                    } else {
+                        pending.extend(data);
+                    }
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+
+        if timed_out {
+            child.kill().ok();
+            child.wait().ok();
+            stdout_handle.join().ok();
+            stderr_handle.join().ok();
+            let _ = sink.send(StreamChunk::Done { truncated: true });
+            return Err(anyhow!("command `{program} {}` timed out", args.join(" ")));
+        }
+
+        if !pending.is_empty() {
+            let _ = sink.send(StreamChunk::Data(std::mem::take(&mut pending)));
+        }
+        let reader_truncated = stdout_handle.join().unwrap_or(false);
+
+        let remaining = timeout.saturating_sub(start.elapsed());
+        let status = match child.wait_timeout(remaining)? {
+            Some(status) => status,
+            None => {
+                child.kill().ok();
+                child.wait().ok();
+                stderr_handle.join().ok();
+                let _ = sink.send(StreamChunk::Done { truncated: true });
+                return Err(anyhow!("command `{program} {}` timed out", args.join(" ")));
+            }
+        };
+        stderr_handle.join().ok();
+
+        let _ = sink.send(StreamChunk::Done {
+            truncated: reader_truncated,
+        });
+
+        if status.success() {
+            return Ok(());
+        }
+
+        let stderr_buf = stderr_buffer.lock().unwrap();
+        let mut argv = vec![program];
+        argv.extend_from_slice(args);
+        Err(anyhow!(format_command_error(
+            &argv,
+            None,
+            status,
+            &stderr_buf.buf
+        )))
+    }
+
     fn run_with_timeout(
         &self,
         timeout: Duration,
@@ -65,11 +275,12 @@ impl CommandRunner {
             .take()
             .ok_or_else(|| anyhow!("failed to capture stderr"))?;
 
-        let buffer = Arc::new(Mutex::new(LimitedBuffer::new(MAX_OUTPUT_SIZE)));
-        let buffer_stdout = buffer.clone();
-        let stdout_handle = std::thread::spawn(move || read_stream(stdout, buffer_stdout));
-        let buffer_stderr = buffer.clone();
-        let stderr_handle = std::thread::spawn(move || read_stream(stderr, buffer_stderr));
+        let stdout_buffer = Arc::new(Mutex::new(LimitedBuffer::new(MAX_OUTPUT_SIZE)));
+        let stdout_buffer_clone = stdout_buffer.clone();
+        let stdout_handle = std::thread::spawn(move || read_stream(stdout, stdout_buffer_clone));
+        let stderr_buffer = Arc::new(Mutex::new(LimitedBuffer::new(MAX_OUTPUT_SIZE)));
+        let stderr_buffer_clone = stderr_buffer.clone();
+        let stderr_handle = std::thread::spawn(move || read_stream(stderr, stderr_buffer_clone));
 
         let status = match child.wait_timeout(timeout)? {
             Some(status) => status,
@@ -78,29 +289,50 @@ impl CommandRunner {
                 child.wait().ok();
                 stdout_handle.join().ok();
                 stderr_handle.join().ok();
-                return Err(anyhow!("command timed out"));
+                return Err(anyhow!("command `{program} {}` timed out", args.join(" ")));
             }
         };
 
         stdout_handle.join().ok();
         stderr_handle.join().ok();
 
-        let buf = buffer.lock().unwrap();
-        let mut output = String::from_utf8_lossy(&buf.buf).to_string();
-        if buf.truncated {
+        let stdout_buf = stdout_buffer.lock().unwrap();
+        let mut output = String::from_utf8_lossy(&stdout_buf.buf).to_string();
+        if stdout_buf.truncated {
             output.push_str("\n[OUTPUT TRUNCATED - Size limit exceeded]");
         }
 
         if status.success() {
-            Ok(output)
-        } else if output.trim().is_empty() {
-            Err(anyhow!("command exited with status {status}"))
-        } else {
-            Err(anyhow!(output))
+            return Ok(output);
         }
+
+        let stderr_buf = stderr_buffer.lock().unwrap();
+        let mut argv = vec![program];
+        argv.extend_from_slice(args);
+        Err(anyhow!(format_command_error(
+            &argv,
+            None,
+            status,
+            &stderr_buf.buf
+        )))
     }
 }
 
+/// Renders a hex digest of `(program, args, env)` for `run_cached`'s cache
+/// key, so the same invocation lands on the same entry across runs.
+/// `DefaultHasher`'s output is fixed for a given build but not guaranteed
+/// across Rust releases; a changed key only misses the cache.
+fn command_cache_key(program: &str, args: &[&str], env: &[(&str, &str)]) -> String {
+    let mut hasher = DefaultHasher::new();
+    program.hash(&mut hasher);
+    args.hash(&mut hasher);
+    for (key, value) in env {
+        key.hash(&mut hasher);
+        value.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
 fn read_stream(mut reader: impl Read + Send + 'static, buffer: Arc<Mutex<LimitedBuffer>>) {
     let mut chunk = [0u8; 8192];
     loop {
@@ -118,6 +350,39 @@ fn read_stream(mut reader: impl Read + Send + 'static, buffer: Arc<Mutex<Limited
     }
 }
 
+/// Like `read_stream`, but forwards each chunk over `tx` instead of
+/// accumulating into a shared buffer. Returns whether output was truncated
+/// at `limit`, mirroring `LimitedBuffer`'s truncation semantics.
+#[allow(dead_code)]
+fn read_stream_chunks(
+    mut reader: impl Read + Send + 'static,
+    tx: Sender<Vec<u8>>,
+    limit: usize,
+) -> bool {
+    let mut chunk = [0u8; 8192];
+    let mut total = 0usize;
+    loop {
+        match reader.read(&mut chunk) {
+            Ok(0) => return false,
+            Ok(n) => {
+                let remaining = limit.saturating_sub(total);
+                if remaining == 0 {
+                    return true;
+                }
+                let to_send = remaining.min(n);
+                total += to_send;
+                if tx.send(chunk[..to_send].to_vec()).is_err() {
+                    return total >= limit;
+                }
+                if to_send < n {
+                    return true;
+                }
+            }
+            Err(_) => return false,
+        }
+    }
+}
+
 struct LimitedBuffer {
     buf: Vec<u8>,
     limit: usize,
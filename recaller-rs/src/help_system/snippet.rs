@@ -0,0 +1,180 @@
+use anyhow::Result;
+
+use crate::help_system::parser::split_command;
+
+/// A single runnable example pulled out of rendered help text, paired with
+/// the description line it was found next to (tldr's "- Do the thing:"
+/// bullet, or the prose a command was inlined into) so the UI can show the
+/// user what the example actually does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snippet {
+    pub command: String,
+    pub label: Option<String>,
+}
+
+/// Scans rendered help text (tldr/man markdown, `$`-prefixed examples,
+/// 4-space indented blocks, and inline `` `backtick` `` spans in prose) and
+/// collects candidate runnable commands. A fenced block opened with a
+/// language hint (e.g. ` ```bash `) is handled the same as a bare ` ``` `
+/// fence, since the whole opening-fence line is skipped either way.
+pub fn extract_snippets(help_text: &str) -> Vec<Snippet> {
+    let mut snippets = Vec::new();
+    let mut in_code_block = false;
+    let mut last_description: Option<String> = None;
+
+    for raw_line in help_text.lines() {
+        let line = raw_line.trim_end();
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        let candidate = if in_code_block {
+            Some(line.trim())
+        } else if let Some(stripped) = line.trim_start().strip_prefix('$') {
+            Some(stripped.trim())
+        } else if line.starts_with("    ") && !line.trim().is_empty() {
+            Some(line.trim())
+        } else {
+            None
+        };
+
+        if let Some(text) = candidate {
+            if !text.is_empty() {
+                snippets.push(Snippet {
+                    command: text.to_string(),
+                    label: last_description.clone(),
+                });
+            }
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let inline_commands = extract_inline_backticks(trimmed);
+        if inline_commands.is_empty() {
+            last_description = Some(strip_bullet(trimmed).to_string());
+            continue;
+        }
+
+        let without_backticks = strip_inline_backticks(trimmed);
+        let label = if without_backticks.trim().trim_matches(':').trim().is_empty() {
+            last_description.clone()
+        } else {
+            Some(trimmed.to_string())
+        };
+
+        for command in inline_commands {
+            snippets.push(Snippet {
+                command,
+                label: label.clone(),
+            });
+        }
+    }
+
+    snippets
+}
+
+/// Collects the contents of every `` `...` `` span on a line.
+fn extract_inline_backticks(line: &str) -> Vec<String> {
+    let mut commands = Vec::new();
+    let mut rest = line;
+
+    while let Some(start) = rest.find('`') {
+        let after_open = &rest[start + 1..];
+        let Some(end) = after_open.find('`') else {
+            break;
+        };
+        let inner = &after_open[..end];
+        if !inner.is_empty() {
+            commands.push(inner.to_string());
+        }
+        rest = &after_open[end + 1..];
+    }
+
+    commands
+}
+
+/// Removes every `` `...` `` span from a line, leaving the surrounding
+/// prose so callers can tell whether the line was *just* a wrapped command.
+fn strip_inline_backticks(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find('`') {
+        let after_open = &rest[start + 1..];
+        let Some(end) = after_open.find('`') else {
+            result.push_str(rest);
+            return result;
+        };
+        result.push_str(&rest[..start]);
+        rest = &after_open[end + 1..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Strips a leading markdown bullet/heading marker (`- `, `* `, `• `, `# `)
+/// from a description line.
+fn strip_bullet(line: &str) -> &str {
+    line.trim_start_matches(['-', '*', '•', '#'])
+        .trim_start()
+}
+
+/// Collects `<placeholder>` and `{{placeholder}}` tokens in order of first
+/// appearance, deduping repeats.
+pub fn extract_placeholders(snippet: &str) -> Vec<String> {
+    let chars: Vec<char> = snippet.chars().collect();
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] == '<' {
+            if let Some(offset) = chars[i..].iter().position(|&c| c == '>') {
+                let token: String = chars[i..=i + offset].iter().collect();
+                push_unique(&mut placeholders, token);
+                i += offset + 1;
+                continue;
+            }
+        } else if chars[i] == '{' && chars.get(i + 1) == Some(&'{') {
+            if let Some(offset) = chars[i + 2..]
+                .windows(2)
+                .position(|w| w == ['}', '}'])
+            {
+                let end = i + 2 + offset + 2;
+                let token: String = chars[i..end].iter().collect();
+                push_unique(&mut placeholders, token);
+                i = end;
+                continue;
+            }
+        }
+        i += 1;
+    }
+
+    placeholders
+}
+
+fn push_unique(list: &mut Vec<String>, token: String) {
+    if !list.contains(&token) {
+        list.push(token);
+    }
+}
+
+/// Replaces every occurrence of each `(placeholder, value)` pair in `snippet`.
+pub fn substitute_placeholders(snippet: &str, values: &[(String, String)]) -> String {
+    let mut result = snippet.to_string();
+    for (placeholder, value) in values {
+        result = result.replace(placeholder.as_str(), value);
+    }
+    result
+}
+
+/// Fills in placeholders and splits the result into argv the same way the
+/// rest of recaller does before executing or copying a command.
+pub fn assemble_command(snippet: &str, values: &[(String, String)]) -> Result<Vec<String>> {
+    let filled = substitute_placeholders(snippet, values);
+    split_command(&filled)
+}
@@ -0,0 +1,29 @@
+mod aws;
+mod cargo;
+mod cheat_sh;
+mod cheats;
+mod docker;
+mod generic;
+mod git;
+mod go_cmd;
+mod kubectl;
+mod man;
+mod npm;
+mod templated;
+mod tldr;
+mod utils;
+
+pub use aws::AwsHelpStrategy;
+pub use cargo::CargoHelpStrategy;
+pub use cheat_sh::CheatShStrategy;
+pub use cheats::CheatsHelpStrategy;
+pub use docker::DockerHelpStrategy;
+pub use generic::GenericHelpStrategy;
+pub use git::GitHelpStrategy;
+pub use go_cmd::GoHelpStrategy;
+pub use kubectl::KubectlHelpStrategy;
+pub use man::ManPageStrategy;
+pub use npm::NpmHelpStrategy;
+pub use templated::TemplatedHelpStrategy;
+pub use tldr::TldrStrategy;
+pub(crate) use utils::remove_overstrike;
@@ -27,12 +27,12 @@ impl HelpStrategy for AwsHelpStrategy {
 
     fn get_help(&self, command: &CommandParts) -> Result<String> {
         if !command.has_sub_command(1) {
-            return self.runner.run("aws", &["help"]);
+            return self.runner.run_cached("aws", &["help"], &[]);
         }
 
         let mut args: Vec<&str> = command.sub_cmds().iter().map(|s| s.as_str()).collect();
         args.push("help");
-        if let Ok(out) = self.runner.run("aws", &args) {
+        if let Ok(out) = self.runner.run_cached("aws", &args, &[]) {
             return Ok(remove_overstrike(&out));
         }
 
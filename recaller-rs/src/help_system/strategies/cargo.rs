@@ -26,10 +26,10 @@ impl HelpStrategy for CargoHelpStrategy {
 
     fn get_help(&self, command: &CommandParts) -> Result<String> {
         if !command.has_sub_command(1) {
-            return self.runner.run("cargo", &["--help"]);
+            return self.runner.run_cached("cargo", &["--help"], &[]);
         }
 
         let sub_cmd = command.get_sub_command(0).unwrap();
-        self.runner.run("cargo", &[sub_cmd, "--help"])
+        self.runner.run_cached("cargo", &[sub_cmd, "--help"], &[])
     }
 }
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+
+use crate::help_system::disk_cache::DiskCache;
+use crate::help_system::runner::CommandRunner;
+use crate::help_system::strategies::remove_overstrike;
+use crate::help_system::strategy::{CommandParts, HelpStrategy};
+
+pub struct CheatShStrategy {
+    runner: Arc<CommandRunner>,
+    cache: Option<DiskCache>,
+    online: bool,
+}
+
+impl CheatShStrategy {
+    pub fn new(runner: Arc<CommandRunner>, cache_duration_hours: u32, online: bool) -> Self {
+        Self {
+            runner,
+            cache: DiskCache::new("cheatsh", cache_duration_hours).ok(),
+            online,
+        }
+    }
+
+    /// Fetches a cheat.sh page with whichever of `curl`/`wget` is on
+    /// `$PATH`, preferring `curl`. Both use `run_short`'s git-style timeout,
+    /// since cheat.sh is meant to answer in well under a second, and `-f`/
+    /// default-error-exit behavior turns a 404 into an `Err` here rather
+    /// than needing to scrape the response body for it.
+    fn fetch(&self, path: &str) -> Result<String> {
+        let output = if self.runner.command_exists("curl") {
+            self.runner.run_short("curl", &["-sS", "-f", path])?
+        } else if self.runner.command_exists("wget") {
+            self.runner.run_short("wget", &["-q", "-O", "-", path])?
+        } else {
+            return Err(anyhow!(
+                "neither curl nor wget is available to query cheat.sh"
+            ));
+        };
+
+        if output.trim().is_empty() {
+            return Err(anyhow!("cheat.sh page empty"));
+        }
+        Ok(output)
+    }
+}
+
+impl HelpStrategy for CheatShStrategy {
+    fn priority(&self) -> i32 {
+        // Last resort: tried only once GenericHelpStrategy's flag-probing
+        // has also come up empty, since it costs a network round trip.
+        9
+    }
+
+    fn supports_command(&self, _: &str) -> bool {
+        true
+    }
+
+    fn get_help(&self, command: &CommandParts) -> Result<String> {
+        let base = command
+            .base_cmd()
+            .ok_or_else(|| anyhow!("no base command provided"))?;
+        let cache_key = command.full_name();
+
+        if let Some(cache) = &self.cache {
+            if let Some(content) = cache.get_fresh(&cache_key) {
+                return Ok(format!("🔖 cheat.sh:\n\n{}", content));
+            }
+        }
+
+        if !self.online {
+            if let Some(stale) = self.cache.as_ref().and_then(|c| c.get_stale(&cache_key)) {
+                return Ok(format!("🔖 cheat.sh:\n\n{}", stale));
+            }
+            return Err(anyhow!(
+                "cheat.sh lookups are disabled (help.online = false)"
+            ));
+        }
+
+        // `?T` asks cheat.sh for the plain-text variant so no ANSI escapes
+        // leak into the UI, regardless of what client fetched it.
+        let path = if command.has_sub_command(1) {
+            format!(
+                "https://cheat.sh/{}/{}?T",
+                base,
+                command.get_sub_command(0).unwrap()
+            )
+        } else {
+            format!("https://cheat.sh/{}?T", base)
+        };
+
+        let content = match self.fetch(&path) {
+            Ok(content) => {
+                let content = remove_overstrike(&content);
+                if let Some(cache) = &self.cache {
+                    cache.store(&cache_key, &content);
+                }
+                content
+            }
+            Err(err) => match self.cache.as_ref().and_then(|c| c.get_stale(&cache_key)) {
+                Some(stale) => stale,
+                None => return Err(err),
+            },
+        };
+
+        Ok(format!("🔖 cheat.sh:\n\n{}", content))
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::{Result, anyhow};
+
+use crate::cheats;
+use crate::help_system::strategy::{CommandParts, HelpStrategy};
+
+/// Surfaces the user's own `.cheat` snippets for a command, ahead of every
+/// generated strategy (vendor-specific help, man pages, tldr, cheat.sh).
+pub struct CheatsHelpStrategy;
+
+impl CheatsHelpStrategy {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl HelpStrategy for CheatsHelpStrategy {
+    fn priority(&self) -> i32 {
+        // Below every other strategy's value: a curated personal cheatsheet
+        // should win over generated docs whenever one exists.
+        1
+    }
+
+    fn supports_command(&self, base_cmd: &str) -> bool {
+        matches!(cheats::load_sheet(base_cmd), Ok(Some(sheet)) if !sheet.entries.is_empty())
+    }
+
+    fn get_help(&self, command: &CommandParts) -> Result<String> {
+        let base = command
+            .base_cmd()
+            .ok_or_else(|| anyhow!("no base command provided"))?;
+        let sheet = cheats::load_sheet(base)?
+            .ok_or_else(|| anyhow!("no cheatsheet saved for {base}"))?;
+        Ok(cheats::render(&sheet))
+    }
+}
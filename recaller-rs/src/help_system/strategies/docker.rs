@@ -25,12 +25,13 @@ impl HelpStrategy for DockerHelpStrategy {
     }
 
     fn get_help(&self, command: &CommandParts) -> Result<String> {
+        let env = [("CLICOLOR_FORCE", "1"), ("FORCE_COLOR", "1")];
         if !command.has_sub_command(1) {
-            return self.runner.run("docker", &["--help"]);
+            return self.runner.run_with_env("docker", &["--help"], &env);
         }
 
         let mut args: Vec<&str> = command.sub_cmds().iter().map(|s| s.as_str()).collect();
         args.push("--help");
-        self.runner.run("docker", &args)
+        self.runner.run_with_env("docker", &args, &env)
     }
 }
@@ -25,11 +25,12 @@ impl HelpStrategy for GoHelpStrategy {
     }
 
     fn get_help(&self, command: &CommandParts) -> Result<String> {
+        let env = [("FORCE_COLOR", "1")];
         if !command.has_sub_command(1) {
-            return self.runner.run("go", &["help"]);
+            return self.runner.run_with_env("go", &["help"], &env);
         }
 
         let sub_cmd = command.get_sub_command(0).unwrap();
-        self.runner.run("go", &["help", sub_cmd])
+        self.runner.run_with_env("go", &["help", sub_cmd], &env)
     }
 }
@@ -17,9 +17,20 @@ impl ManPageStrategy {
     }
 }
 
+/// Forces `man` to emit plain text to stdout instead of paging through
+/// `less` or re-flowing for a terminal that isn't there.
+const NON_INTERACTIVE_ENV: &[(&str, &str)] = &[
+    ("MANPAGER", "cat"),
+    ("PAGER", "cat"),
+    ("MAN_KEEP_FORMATTING", "0"),
+];
+
 impl HelpStrategy for ManPageStrategy {
     fn priority(&self) -> i32 {
-        5
+        // Just below npm/git/go/kubectl/etc.: tried once those tool-specific
+        // strategies decline, but well before GenericHelpStrategy's raw
+        // flag-probing or the network cheat.sh fallback.
+        3
     }
 
     fn supports_command(&self, base_cmd: &str) -> bool {
@@ -38,7 +49,24 @@ impl HelpStrategy for ManPageStrategy {
         let base = command
             .base_cmd()
             .ok_or_else(|| anyhow!("missing command"))?;
-        let output = self.runner.run("man", &[base])?;
+
+        // Try the conventional `cmd-subcmd` page (e.g. `git-commit`) before
+        // falling back to the top-level page.
+        if command.has_sub_command(1) {
+            let page = format!("{base}-{}", command.get_sub_command(0).unwrap());
+            if let Ok(output) =
+                self.runner
+                    .run_with_env("man", &[page.as_str()], NON_INTERACTIVE_ENV)
+            {
+                if !output.contains("No manual entry") && !output.contains("has been minimized") {
+                    return Ok(remove_overstrike(&output));
+                }
+            }
+        }
+
+        let output = self
+            .runner
+            .run_with_env("man", &[base], NON_INTERACTIVE_ENV)?;
         if output.contains("No manual entry") || output.contains("has been minimized") {
             return Err(anyhow!("man page not found for {base}"));
         }
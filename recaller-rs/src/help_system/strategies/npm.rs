@@ -27,14 +27,14 @@ impl HelpStrategy for NpmHelpStrategy {
 
     fn get_help(&self, command: &CommandParts) -> Result<String> {
         if !command.has_sub_command(1) {
-            return self.runner.run("npm", &["help"]);
+            return self.runner.run_cached("npm", &["help"], &[]);
         }
 
         let sub_cmd = command.get_sub_command(0).unwrap();
-        if let Ok(out) = self.runner.run("npm", &["help", sub_cmd]) {
+        if let Ok(out) = self.runner.run_cached("npm", &["help", sub_cmd], &[]) {
             return Ok(remove_overstrike(&out));
         }
 
-        self.runner.run("npm", &[sub_cmd, "--help"])
+        self.runner.run_cached("npm", &[sub_cmd, "--help"], &[])
     }
 }
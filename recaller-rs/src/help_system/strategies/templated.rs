@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+
+use crate::config::CustomHelpCommand;
+use crate::help_system::parser::split_command;
+use crate::help_system::runner::CommandRunner;
+use crate::help_system::strategy::{CommandParts, HelpStrategy};
+
+/// Runs a user-configured help command built from a `CustomHelpCommand`
+/// template, letting users teach recaller about tools like `terraform`
+/// without a bespoke strategy.
+pub struct TemplatedHelpStrategy {
+    runner: Arc<CommandRunner>,
+    entry: CustomHelpCommand,
+}
+
+impl TemplatedHelpStrategy {
+    pub fn new(runner: Arc<CommandRunner>, entry: CustomHelpCommand) -> Self {
+        Self { runner, entry }
+    }
+}
+
+impl HelpStrategy for TemplatedHelpStrategy {
+    fn priority(&self) -> i32 {
+        self.entry.priority
+    }
+
+    fn supports_command(&self, base_cmd: &str) -> bool {
+        base_cmd == self.entry.base_cmd
+    }
+
+    fn get_help(&self, command: &CommandParts) -> Result<String> {
+        let sub_cmds = command.sub_cmds().join(" ");
+        let rendered = self
+            .entry
+            .template
+            .replace("{cmd}", &self.entry.base_cmd)
+            .replace("{subcmds}", sub_cmds.trim())
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let tokens = split_command(&rendered)?;
+        let (program, args) = tokens
+            .split_first()
+            .ok_or_else(|| anyhow!("empty help template for {}", self.entry.base_cmd))?;
+        let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+
+        if self.entry.env.is_empty() {
+            self.runner.run(program, &args)
+        } else {
+            let env: Vec<(&str, &str)> = self
+                .entry
+                .env
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect();
+            self.runner.run_with_env(program, &args, &env)
+        }
+    }
+}
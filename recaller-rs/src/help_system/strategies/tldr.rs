@@ -1,24 +1,85 @@
-use std::io::Read;
-use std::time::Duration;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use anyhow::{Context, Result, anyhow};
+use directories::BaseDirs;
 
+use crate::help_system::runner::CommandRunner;
 use crate::help_system::strategy::{CommandParts, HelpStrategy};
+use crate::platform::tldr_platform_dir;
 
-const TLDR_TIMEOUT: Duration = Duration::from_secs(10);
-const MAX_TLDR_SIZE: usize = 512 * 1024;
+const TLDR_REPO_URL: &str = "https://github.com/tldr-pages/tldr.git";
 
-pub struct TldrStrategy;
+pub struct TldrStrategy {
+    runner: Arc<CommandRunner>,
+    repo_path: PathBuf,
+}
 
 impl TldrStrategy {
-    pub fn new() -> Self {
-        Self
+    pub fn new(runner: Arc<CommandRunner>, repo_path: Option<String>) -> Self {
+        let repo_path = repo_path
+            .map(PathBuf::from)
+            .unwrap_or_else(default_repo_path);
+        Self { runner, repo_path }
+    }
+
+    /// Shallow-clones the tldr-pages repo into `repo_path` the first time
+    /// it's needed; later calls just confirm the clone is still there, so
+    /// every lookup after the first is a plain file read instead of a
+    /// network round trip.
+    fn ensure_cloned(&self) -> Result<()> {
+        if self.repo_path.join(".git").is_dir() {
+            return Ok(());
+        }
+
+        if let Some(parent) = self.repo_path.parent() {
+            fs::create_dir_all(parent).context("failed to create tldr cache directory")?;
+        }
+
+        self.runner.run_git(
+            "git",
+            &[
+                "clone",
+                "--depth",
+                "1",
+                TLDR_REPO_URL,
+                &self.repo_path.to_string_lossy(),
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn page_file(&self, platform_dir: &str, command: &CommandParts, base: &str) -> PathBuf {
+        let file_name = if command.has_sub_command(1) {
+            format!("{}-{}.md", base, command.get_sub_command(0).unwrap())
+        } else {
+            format!("{base}.md")
+        };
+        self.repo_path.join("pages").join(platform_dir).join(file_name)
+    }
+
+    fn read_page(&self, command: &CommandParts, base: &str) -> Result<String> {
+        let platform_dir = tldr_platform_dir();
+        if platform_dir != "common" {
+            let platform_path = self.page_file(platform_dir, command, base);
+            if let Ok(raw) = fs::read_to_string(&platform_path) {
+                return Ok(raw);
+            }
+        }
+
+        let common_path = self.page_file("common", command, base);
+        fs::read_to_string(&common_path)
+            .with_context(|| format!("no tldr page for {base} at {}", common_path.display()))
     }
 }
 
 impl HelpStrategy for TldrStrategy {
     fn priority(&self) -> i32 {
-        0
+        // Just below ManPageStrategy: only tried once the system's own man
+        // page has nothing, so terse/missing man pages get filled in with
+        // tldr's example-first summary instead of being skipped outright.
+        4
     }
 
     fn supports_command(&self, _: &str) -> bool {
@@ -30,48 +91,79 @@ impl HelpStrategy for TldrStrategy {
             .base_cmd()
             .ok_or_else(|| anyhow!("no base command provided"))?;
 
-        let base_url =
-            "https://raw.githubusercontent.com/tldr-pages/tldr/refs/heads/main/pages/common";
-        let path = if command.has_sub_command(1) {
-            format!(
-                "{}/{}-{}.md",
-                base_url,
-                base,
-                command.get_sub_command(0).unwrap()
-            )
-        } else {
-            format!("{}/{}.md", base_url, base)
-        };
+        self.ensure_cloned()?;
+        let raw = self.read_page(command, base)?;
+        let parsed = parse_tldr_page(&raw);
+        if parsed.trim().is_empty() {
+            return Err(anyhow!("tldr page for {base} was empty"));
+        }
+
+        Ok(format!("📚 TLDR Documentation:\n\n{}", parsed))
+    }
+}
 
-        let response = ureq::get(&path)
-            .timeout(TLDR_TIMEOUT)
-            .call()
-            .with_context(|| format!("failed to fetch TLDR page from {path}"))?;
+/// Renders a tldr-pages markdown page into clean plain text: the `#` title
+/// and `>` description lines lose their markers, each `- ...:` example
+/// description gets its own line, and the backtick-wrapped command template
+/// beneath it is unwrapped with its `{{placeholder}}` tokens rendered as
+/// `<placeholder>`.
+fn parse_tldr_page(raw: &str) -> String {
+    let mut out = String::new();
+    for line in raw.lines() {
+        let line = line.trim_end();
+        let trimmed = line.trim_start();
 
-        if response.status() != 200 {
-            return Err(anyhow!(
-                "TLDR page not found for {base} (HTTP {})",
-                response.status()
-            ));
+        if let Some(rest) = trimmed.strip_prefix("# ") {
+            out.push_str(rest.trim());
+            out.push('\n');
+        } else if let Some(rest) = trimmed.strip_prefix("> ") {
+            out.push_str(rest.trim());
+            out.push('\n');
+        } else if let Some(rest) = trimmed.strip_prefix("- ") {
+            out.push('\n');
+            out.push_str(rest.trim());
+            out.push('\n');
+        } else if trimmed.starts_with('`') {
+            let command = trimmed.trim_matches('`');
+            out.push_str("  ");
+            out.push_str(&render_placeholders(command));
+            out.push('\n');
+        } else if !trimmed.is_empty() {
+            out.push_str(trimmed);
+            out.push('\n');
         }
+    }
+    out.trim_end().to_string()
+}
 
-        let mut reader = response.into_reader();
-        let mut buffer = Vec::new();
-        let mut chunk = [0u8; 8192];
-        while buffer.len() < MAX_TLDR_SIZE {
-            let read_len = std::cmp::min(chunk.len(), MAX_TLDR_SIZE - buffer.len());
-            match reader.read(&mut chunk[..read_len]) {
-                Ok(0) => break,
-                Ok(n) => buffer.extend_from_slice(&chunk[..n]),
-                Err(err) => return Err(anyhow!("failed to read TLDR response: {err}")),
+/// Turns each `{{name}}` token into `<name>`, leaving an unterminated `{{`
+/// as-is rather than swallowing the rest of the line.
+fn render_placeholders(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+        match rest.find("}}") {
+            Some(end) => {
+                out.push('<');
+                out.push_str(rest[..end].trim());
+                out.push('>');
+                rest = &rest[end + 2..];
+            }
+            None => {
+                out.push_str("{{");
+                break;
             }
         }
+    }
+    out.push_str(rest);
+    out
+}
 
-        let mut content = String::from_utf8_lossy(&buffer).to_string();
-        if content.is_empty() {
-            return Err(anyhow!("TLDR page empty"));
-        }
-        content = format!("ðŸ“š TLDR Documentation:\n\n{}", content);
-        Ok(content)
+fn default_repo_path() -> PathBuf {
+    match BaseDirs::new() {
+        Some(base) => base.cache_dir().join("recaller").join("tldr"),
+        None => PathBuf::from(".cache/recaller/tldr"),
     }
 }
@@ -5,8 +5,13 @@ use std::path::PathBuf;
 
 use chrono::{DateTime, Duration, TimeZone, Utc};
 use directories::BaseDirs;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use regex::{Regex, RegexSet};
 use thiserror::Error;
 
+use crate::config::{FrecencyConfig, RedactionConfig};
+
 #[derive(Debug, Clone)]
 pub struct HistoryEntry {
     pub command: String,
@@ -26,6 +31,10 @@ pub struct RankedCommand {
     pub command: String,
     pub score: f64,
     pub metadata: CommandMetadata,
+    /// Char indices into `command` that matched the search query, used to
+    /// render highlighted spans in the results list. Empty for an empty
+    /// query (nothing to highlight).
+    pub match_indices: Vec<usize>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -34,7 +43,11 @@ pub struct HistoryIndex {
 }
 
 impl HistoryIndex {
-    pub fn from_entries(entries: Vec<HistoryEntry>) -> Self {
+    pub fn from_entries(entries: Vec<HistoryEntry>, redaction: &RedactionConfig) -> Self {
+        let ignore_set = build_redaction_set(&redaction.patterns);
+        let ignore_exact: std::collections::HashSet<&str> =
+            redaction.exact.iter().map(String::as_str).collect();
+
         let capacity = entries.len().max(1);
         let mut freq_map: HashMap<String, i32> = HashMap::with_capacity(capacity / 4 + 1);
         let mut last_timestamp: HashMap<String, DateTime<Utc>> =
@@ -48,6 +61,9 @@ impl HistoryIndex {
             if command.is_empty() {
                 continue;
             }
+            if ignore_exact.contains(command) || ignore_set.is_match(command) {
+                continue;
+            }
             let command = command.to_string();
 
             *freq_map.entry(command.clone()).or_insert(0) += 1;
@@ -79,19 +95,25 @@ impl HistoryIndex {
         HistoryIndex { commands }
     }
 
-    pub fn search(&self, query: &str, enable_fuzzing: bool) -> Vec<RankedCommand> {
-        let nodes: Vec<&CommandMetadata> = if enable_fuzzing {
+    pub fn search(
+        &self,
+        query: &str,
+        enable_fuzzing: bool,
+        frecency: &FrecencyConfig,
+    ) -> Vec<RankedCommand> {
+        let matched: Vec<(&CommandMetadata, Vec<usize>)> = if enable_fuzzing {
             self.search_fuzzy(query)
         } else {
             self.search_prefix(query)
         };
 
-        let mut ranked: Vec<RankedCommand> = nodes
+        let mut ranked: Vec<RankedCommand> = matched
             .into_iter()
-            .map(|meta| RankedCommand {
+            .map(|(meta, match_indices)| RankedCommand {
                 command: meta.command.clone(),
-                score: calculate_score(meta),
+                score: calculate_score(meta, frecency),
                 metadata: meta.clone(),
+                match_indices,
             })
             .collect();
 
@@ -103,9 +125,11 @@ impl HistoryIndex {
         ranked
     }
 
-    fn search_prefix(&self, prefix: &str) -> Vec<&CommandMetadata> {
+    /// Prefix matches always highlight from the start of the command, so the
+    /// match indices are simply the first `prefix.chars().count()` positions.
+    fn search_prefix(&self, prefix: &str) -> Vec<(&CommandMetadata, Vec<usize>)> {
         if prefix.is_empty() {
-            return self.commands.values().collect();
+            return self.commands.values().map(|meta| (meta, Vec::new())).collect();
         }
 
         use std::ops::Bound::{Excluded, Included};
@@ -115,27 +139,59 @@ impl HistoryIndex {
         upper.push(char::MAX);
         let end = Excluded(upper);
 
+        let match_len = prefix.chars().count();
         self.commands
             .range((start, end))
-            .map(|(_, meta)| meta)
+            .map(|(_, meta)| (meta, (0..match_len).collect()))
             .collect()
     }
 
-    fn search_fuzzy(&self, query: &str) -> Vec<&CommandMetadata> {
+    /// Skim-style fuzzy matching: ranks and highlights commands by the
+    /// char positions `SkimMatcherV2` picked as the best alignment of
+    /// `query` within each command, same idea as help search highlighting.
+    fn search_fuzzy(&self, query: &str) -> Vec<(&CommandMetadata, Vec<usize>)> {
         if query.is_empty() {
-            return self.commands.values().collect();
+            return self.commands.values().map(|meta| (meta, Vec::new())).collect();
         }
-        let query_lower = query.to_lowercase();
+        let matcher = SkimMatcherV2::default();
         self.commands
             .values()
-            .filter(|meta| meta.command.to_lowercase().contains(&query_lower))
+            .filter_map(|meta| {
+                matcher
+                    .fuzzy_indices(&meta.command, query)
+                    .map(|(_score, indices)| (meta, indices))
+            })
             .collect()
     }
 }
 
-fn calculate_score(metadata: &CommandMetadata) -> f64 {
-    let frequency_score = metadata.frequency as f64;
-    let recency_score = match metadata.timestamp {
+/// Compiles each redaction pattern independently so a single invalid
+/// user-added regex can't silently disable every pattern, including the
+/// built-in `password=`/`token`/`secret`/bearer defaults — that would let
+/// exactly the secrets this config exists to hide flow into the index.
+fn build_redaction_set(patterns: &[String]) -> RegexSet {
+    let valid: Vec<&str> = patterns
+        .iter()
+        .filter(|pattern| {
+            let ok = Regex::new(pattern).is_ok();
+            if !ok {
+                eprintln!("⚠️ ignoring invalid redaction pattern: {pattern}");
+            }
+            ok
+        })
+        .map(String::as_str)
+        .collect();
+
+    RegexSet::new(valid).unwrap_or_else(|_| RegexSet::empty())
+}
+
+/// zoxide-style frecency: `rank` (the accumulated run count) times an
+/// `age_factor` bucketed off how recently the command last ran, so a
+/// command run once a year ago can't outrank one run five times this
+/// morning the way a flat frequency/recency blend would.
+fn calculate_score(metadata: &CommandMetadata, frecency: &FrecencyConfig) -> f64 {
+    let rank = metadata.frequency as f64;
+    let age_factor = match metadata.timestamp {
         Some(ts) => {
             let delta = Utc::now().signed_duration_since(ts).num_seconds();
             let hours = if delta.is_negative() {
@@ -143,18 +199,28 @@ fn calculate_score(metadata: &CommandMetadata) -> f64 {
             } else {
                 delta as f64 / 3600.0
             };
-            1.0 / (hours + 1.0)
+            if hours <= frecency.recent_hours {
+                frecency.recent_multiplier
+            } else if hours <= frecency.day_hours {
+                frecency.day_multiplier
+            } else if hours <= frecency.week_hours {
+                frecency.week_multiplier
+            } else {
+                frecency.stale_multiplier
+            }
         }
-        None => 0.0,
+        None => frecency.stale_multiplier,
     };
 
-    (0.6 * frequency_score) + (0.4 * recency_score)
+    rank * age_factor
 }
 
 #[derive(Debug, Clone, Copy)]
 enum ShellKind {
     Zsh,
     Bash,
+    Fish,
+    Atuin,
 }
 
 #[derive(Debug, Error)]
@@ -171,27 +237,94 @@ pub enum HistoryError {
     },
     #[error("unsupported shell '{0}' detected")]
     UnknownShell(String),
+    #[error("failed to read {source} history store: {message}")]
+    Import { source: String, message: String },
 }
 
-pub fn load_history_index() -> Result<HistoryIndex, HistoryError> {
-    let shell = detect_shell()?;
-    let entries = match shell {
-        ShellKind::Zsh => read_zsh_history()?,
-        ShellKind::Bash => read_bash_history()?,
-    };
+/// A source of shell history, abstracted behind a single `read` so
+/// `load_history_index` doesn't need to know whether entries came from a
+/// plain-text history file or a sqlite store.
+trait HistoryImporter {
+    fn read(&self) -> Result<Vec<HistoryEntry>, HistoryError>;
+}
 
-    Ok(HistoryIndex::from_entries(entries))
+struct ZshImporter;
+struct BashImporter;
+struct FishImporter;
+struct AtuinImporter;
+
+impl HistoryImporter for ZshImporter {
+    fn read(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        read_zsh_history()
+    }
 }
 
-pub fn get_suggestions(index: &HistoryIndex, query: &str, enable_fuzzing: bool) -> Vec<String> {
+impl HistoryImporter for BashImporter {
+    fn read(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        read_bash_history()
+    }
+}
+
+impl HistoryImporter for FishImporter {
+    fn read(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        read_fish_history()
+    }
+}
+
+impl HistoryImporter for AtuinImporter {
+    fn read(&self) -> Result<Vec<HistoryEntry>, HistoryError> {
+        read_atuin_history()
+    }
+}
+
+fn importer_for(shell: ShellKind) -> Box<dyn HistoryImporter> {
+    match shell {
+        ShellKind::Zsh => Box::new(ZshImporter),
+        ShellKind::Bash => Box::new(BashImporter),
+        ShellKind::Fish => Box::new(FishImporter),
+        ShellKind::Atuin => Box::new(AtuinImporter),
+    }
+}
+
+/// Loads history from whichever source `source_override` names (`"zsh"`,
+/// `"bash"`, `"fish"`, or `"atuin"`, matching `HistoryConfig::source_override`),
+/// falling back to detecting the shell from `$SHELL` when it's `None`.
+pub fn load_history_index(
+    source_override: Option<&str>,
+    redaction: &RedactionConfig,
+) -> Result<HistoryIndex, HistoryError> {
+    let shell = detect_shell(source_override)?;
+    let entries = importer_for(shell).read()?;
+    Ok(HistoryIndex::from_entries(entries, redaction))
+}
+
+pub fn get_suggestions(
+    index: &HistoryIndex,
+    query: &str,
+    enable_fuzzing: bool,
+    frecency: &FrecencyConfig,
+) -> Vec<String> {
     index
-        .search(query, enable_fuzzing)
+        .search(query, enable_fuzzing, frecency)
         .into_iter()
         .map(|ranked| ranked.command)
         .collect()
 }
 
-fn detect_shell() -> Result<ShellKind, HistoryError> {
+/// Picks the `HistoryImporter` to use: `source_override` (from
+/// `HistoryConfig::source_override`) wins when set, otherwise the shell is
+/// detected from `$SHELL`'s basename.
+fn detect_shell(source_override: Option<&str>) -> Result<ShellKind, HistoryError> {
+    if let Some(source) = source_override {
+        return match source {
+            "zsh" => Ok(ShellKind::Zsh),
+            "bash" => Ok(ShellKind::Bash),
+            "fish" => Ok(ShellKind::Fish),
+            "atuin" => Ok(ShellKind::Atuin),
+            other => Err(HistoryError::UnknownShell(other.to_string())),
+        };
+    }
+
     let shell_path = std::env::var("SHELL").unwrap_or_else(|_| "/bin/bash".to_string());
     let shell_name = PathBuf::from(shell_path)
         .file_name()
@@ -202,6 +335,7 @@ fn detect_shell() -> Result<ShellKind, HistoryError> {
     match shell_name.as_str() {
         "zsh" => Ok(ShellKind::Zsh),
         "bash" => Ok(ShellKind::Bash),
+        "fish" => Ok(ShellKind::Fish),
         other => Err(HistoryError::UnknownShell(other.to_string())),
     }
 }
@@ -310,3 +444,154 @@ fn history_path(filename: &str) -> Result<PathBuf, HistoryError> {
         .ok_or_else(|| HistoryError::DetectShell("Failed to resolve home directory".to_string()))?;
     Ok(base.home_dir().join(filename))
 }
+
+/// Resolves a path under the XDG data directory (`~/.local/share` on
+/// Linux), joining each of `segments` in order.
+fn data_dir_path(segments: &[&str]) -> Result<PathBuf, HistoryError> {
+    let base = BaseDirs::new()
+        .ok_or_else(|| HistoryError::DetectShell("Failed to resolve home directory".to_string()))?;
+    let mut path = base.data_dir().to_path_buf();
+    for segment in segments {
+        path.push(segment);
+    }
+    Ok(path)
+}
+
+/// Parses fish's `fish_history` file, a YAML-ish stream of
+/// `- cmd: <command>` records each followed by a `  when: <epoch>` line.
+/// Multi-line commands are stored `\n`-escaped; we unescape them back.
+fn read_fish_history() -> Result<Vec<HistoryEntry>, HistoryError> {
+    let path = data_dir_path(&["fish", "fish_history"])?;
+    let file = File::open(&path).map_err(|err| match err.kind() {
+        std::io::ErrorKind::NotFound => HistoryError::MissingHistoryFile {
+            shell: "fish".to_string(),
+            help: format!(
+                "Run some commands in fish to create {} and then try again",
+                path.display()
+            ),
+        },
+        _ => HistoryError::Io {
+            path: path.clone(),
+            source: err,
+        },
+    })?;
+
+    let reader = BufReader::new(file);
+    let mut history = Vec::new();
+    let mut pending_command: Option<String> = None;
+
+    for line in reader.lines() {
+        let line = line.map_err(|err| HistoryError::Io {
+            path: path.clone(),
+            source: err,
+        })?;
+
+        if let Some(raw) = line.strip_prefix("- cmd: ") {
+            if let Some(command) = pending_command.take() {
+                history.push(HistoryEntry {
+                    command,
+                    timestamp: None,
+                });
+            }
+            pending_command = Some(unescape_fish_command(raw));
+            continue;
+        }
+
+        if let Some(raw) = line.trim_start().strip_prefix("when: ") {
+            if let Some(command) = pending_command.take() {
+                let timestamp = raw
+                    .trim()
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|epoch| Utc.timestamp_opt(epoch, 0).single());
+                history.push(HistoryEntry { command, timestamp });
+            }
+        }
+    }
+
+    if let Some(command) = pending_command.take() {
+        history.push(HistoryEntry {
+            command,
+            timestamp: None,
+        });
+    }
+
+    Ok(history)
+}
+
+/// Undoes fish's history escaping in a single left-to-right pass: `\n`
+/// becomes a real newline and `\\` becomes a literal backslash. Doing this
+/// as two sequential global replaces (first `\n`, then `\\`) re-interprets
+/// an escaped backslash followed by a literal `n` (stored as `\\n`) as a
+/// newline escape, corrupting any command containing a literal `\n`.
+fn unescape_fish_command(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.peek() {
+                Some('n') => {
+                    chars.next();
+                    out.push('\n');
+                }
+                Some('\\') => {
+                    chars.next();
+                    out.push('\\');
+                }
+                _ => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Reads atuin's sqlite history store directly (`~/.local/share/atuin/history.db`).
+fn read_atuin_history() -> Result<Vec<HistoryEntry>, HistoryError> {
+    let path = data_dir_path(&["atuin", "history.db"])?;
+    if !path.exists() {
+        return Err(HistoryError::MissingHistoryFile {
+            shell: "atuin".to_string(),
+            help: format!(
+                "Run some commands with atuin enabled to create {} and then try again",
+                path.display()
+            ),
+        });
+    }
+
+    let conn = rusqlite::Connection::open(&path).map_err(|err| HistoryError::Import {
+        source: "atuin".to_string(),
+        message: err.to_string(),
+    })?;
+
+    let mut stmt = conn
+        .prepare("SELECT command, timestamp FROM history")
+        .map_err(|err| HistoryError::Import {
+            source: "atuin".to_string(),
+            message: err.to_string(),
+        })?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            let command: String = row.get(0)?;
+            let timestamp_nanos: i64 = row.get(1)?;
+            Ok((command, timestamp_nanos))
+        })
+        .map_err(|err| HistoryError::Import {
+            source: "atuin".to_string(),
+            message: err.to_string(),
+        })?;
+
+    let mut history = Vec::new();
+    for row in rows {
+        let (command, timestamp_nanos) = row.map_err(|err| HistoryError::Import {
+            source: "atuin".to_string(),
+            message: err.to_string(),
+        })?;
+        let timestamp = Utc.timestamp_opt(timestamp_nanos / 1_000_000_000, 0).single();
+        history.push(HistoryEntry { command, timestamp });
+    }
+
+    Ok(history)
+}
@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+/// A user-triggerable action within the filesystem UI. Config maps key
+/// chords to these so `handle_key` dispatches on intent rather than on
+/// hard-coded `KeyCode`s, following the keymap-config pattern from
+/// yazi/xplr.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    Open,
+    CopyPath,
+    CycleFilter,
+    ToggleMounts,
+    ToggleHelp,
+    FocusMetadata,
+    ScrollUp,
+    ScrollDown,
+    Quit,
+    Trash,
+    Reveal,
+}
+
+impl Action {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Open => "Open file/directory",
+            Action::CopyPath => "Copy selected path",
+            Action::CycleFilter => "Cycle filter (All/Dirs/Files)",
+            Action::ToggleMounts => "Toggle mounted filesystems view",
+            Action::ToggleHelp => "Toggle this help window",
+            Action::FocusMetadata => "Toggle metadata/preview focus",
+            Action::ScrollUp => "Navigate up / scroll up",
+            Action::ScrollDown => "Navigate down / scroll down",
+            Action::Quit => "Exit UI",
+            Action::Trash => "Move selected file to trash",
+            Action::Reveal => "Reveal selected file in file manager",
+        }
+    }
+
+    fn short_label(&self) -> &'static str {
+        match self {
+            Action::Open => "open",
+            Action::CopyPath => "copy path",
+            Action::CycleFilter => "toggle filter",
+            Action::ToggleMounts => "mounts",
+            Action::ToggleHelp => "help",
+            Action::FocusMetadata => "focus metadata",
+            Action::ScrollUp | Action::ScrollDown => "navigate",
+            Action::Quit => "quit",
+            Action::Trash => "trash",
+            Action::Reveal => "reveal",
+        }
+    }
+}
+
+/// One human-editable binding as it appears in `.recaller.yaml`, e.g.
+/// `{ key: "ctrl+y", action: CopyPath }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub key: String,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilesystemKeymapConfig {
+    #[serde(default = "default_filesystem_bindings")]
+    pub bindings: Vec<KeyBinding>,
+}
+
+impl Default for FilesystemKeymapConfig {
+    fn default() -> Self {
+        Self {
+            bindings: default_filesystem_bindings(),
+        }
+    }
+}
+
+fn default_filesystem_bindings() -> Vec<KeyBinding> {
+    vec![
+        KeyBinding {
+            key: "enter".into(),
+            action: Action::Open,
+        },
+        KeyBinding {
+            key: "ctrl+y".into(),
+            action: Action::CopyPath,
+        },
+        KeyBinding {
+            key: "ctrl+t".into(),
+            action: Action::CycleFilter,
+        },
+        KeyBinding {
+            key: "ctrl+m".into(),
+            action: Action::ToggleMounts,
+        },
+        KeyBinding {
+            key: "ctrl+h".into(),
+            action: Action::ToggleHelp,
+        },
+        KeyBinding {
+            key: "tab".into(),
+            action: Action::FocusMetadata,
+        },
+        KeyBinding {
+            key: "up".into(),
+            action: Action::ScrollUp,
+        },
+        KeyBinding {
+            key: "down".into(),
+            action: Action::ScrollDown,
+        },
+        KeyBinding {
+            key: "esc".into(),
+            action: Action::Quit,
+        },
+        KeyBinding {
+            key: "ctrl+c".into(),
+            action: Action::Quit,
+        },
+        KeyBinding {
+            key: "ctrl+d".into(),
+            action: Action::Trash,
+        },
+        KeyBinding {
+            key: "ctrl+r".into(),
+            action: Action::Reveal,
+        },
+    ]
+}
+
+/// Resolves `KeyEvent`s to `Action`s per the active bindings, and generates
+/// the footer hint / help modal text so neither drifts from what is
+/// actually bound.
+pub struct Keymap {
+    bindings: HashMap<(KeyCode, KeyModifiers), Action>,
+}
+
+impl Keymap {
+    pub fn from_config(config: &FilesystemKeymapConfig) -> Self {
+        let mut bindings = HashMap::new();
+        for binding in &config.bindings {
+            if let Some(chord) = parse_key_spec(&binding.key) {
+                bindings.insert(chord, binding.action);
+            }
+        }
+        Self { bindings }
+    }
+
+    pub fn resolve(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<Action> {
+        self.bindings.get(&(code, modifiers)).copied()
+    }
+
+    /// One formatted "key - description" line per binding, sorted for
+    /// stable display, used to populate the help modal.
+    pub fn help_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .bindings
+            .iter()
+            .map(|(&(code, modifiers), action)| {
+                format!(
+                    "  {:<14}- {}",
+                    format_key_spec(code, modifiers),
+                    action.description()
+                )
+            })
+            .collect();
+        lines.sort();
+        lines
+    }
+
+    /// A compact `"Key: label  Key: label"` string for the footer, in the
+    /// order the caller asks for.
+    pub fn footer_hint(&self, actions: &[Action]) -> String {
+        actions
+            .iter()
+            .filter_map(|action| {
+                self.bindings
+                    .iter()
+                    .find(|(_, bound)| *bound == action)
+                    .map(|(&(code, modifiers), _)| {
+                        format!("{}: {}", format_key_spec(code, modifiers), action.short_label())
+                    })
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+}
+
+/// Parses a spec like `"ctrl+y"`, `"shift+tab"`, `"enter"`, or `"a"` into a
+/// `(KeyCode, KeyModifiers)` chord.
+fn parse_key_spec(spec: &str) -> Option<(KeyCode, KeyModifiers)> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut code = None;
+    for part in spec.split('+') {
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" => modifiers |= KeyModifiers::CONTROL,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "enter" => code = Some(KeyCode::Enter),
+            "esc" | "escape" => code = Some(KeyCode::Esc),
+            "tab" => code = Some(KeyCode::Tab),
+            "backspace" => code = Some(KeyCode::Backspace),
+            "up" => code = Some(KeyCode::Up),
+            "down" => code = Some(KeyCode::Down),
+            "left" => code = Some(KeyCode::Left),
+            "right" => code = Some(KeyCode::Right),
+            other => {
+                let mut chars = other.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => code = Some(KeyCode::Char(c)),
+                    _ => return None,
+                }
+            }
+        }
+    }
+    code.map(|code| (code, modifiers))
+}
+
+fn format_key_spec(code: KeyCode, modifiers: KeyModifiers) -> String {
+    let mut parts = Vec::new();
+    if modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    if modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    parts.push(match code {
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Char(c) => c.to_ascii_uppercase().to_string(),
+        _ => "?".to_string(),
+    });
+    parts.join("+")
+}
@@ -1,4 +1,5 @@
 mod banner;
+mod cheats;
 mod cli;
 mod commands;
 mod config;
@@ -7,16 +8,19 @@ mod fs;
 mod help;
 mod help_system;
 mod history;
+mod keymap;
 mod platform;
+mod process;
 mod state;
 mod ui;
 mod version;
 
 use clap::Parser;
 
-use crate::cli::{Cli, Commands};
+use crate::cli::{Cli, Commands, RunArgs};
 use crate::commands::{
-    handle_fs, handle_history, handle_run, handle_settings, handle_usage, handle_version,
+    handle_cheats, handle_fs, handle_history, handle_init, handle_run, handle_settings,
+    handle_usage, handle_version,
 };
 use crate::config::Config;
 use crate::state::AppState;
@@ -33,10 +37,10 @@ fn main() {
     };
 
     let mut state = AppState::new(config);
-    let command = cli.command.unwrap_or(Commands::Run);
+    let command = cli.command.unwrap_or(Commands::Run(RunArgs::default()));
 
     let result = match command {
-        Commands::Run => handle_run(&mut state),
+        Commands::Run(args) => handle_run(&mut state, &args),
         Commands::Usage => {
             handle_usage();
             Ok(())
@@ -44,6 +48,8 @@ fn main() {
         Commands::History(args) => handle_history(&mut state, &args),
         Commands::Fs(args) => handle_fs(&mut state, &args),
         Commands::Settings(args) => handle_settings(&args),
+        Commands::Init(args) => handle_init(&args),
+        Commands::Cheats(args) => handle_cheats(&args),
         Commands::Version => {
             handle_version();
             Ok(())
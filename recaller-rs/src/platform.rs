@@ -1,6 +1,25 @@
 use anyhow::{Context, Result, anyhow};
 use arboard::Clipboard;
-use std::process::Command;
+
+use crate::config::TerminalTarget;
+use crate::process::{run_command, spawn_detached};
+
+/// Returns the tldr-pages directory name for the current OS (e.g. `osx`, `linux`).
+pub fn tldr_platform_dir() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "osx"
+    } else if cfg!(target_os = "linux") {
+        "linux"
+    } else if cfg!(target_os = "windows") {
+        "windows"
+    } else if cfg!(target_os = "android") {
+        "android"
+    } else if cfg!(target_os = "solaris") {
+        "sunos"
+    } else {
+        "common"
+    }
+}
 
 pub fn copy_to_clipboard(text: &str) -> Result<()> {
     let mut clipboard = Clipboard::new().context("failed to access clipboard")?;
@@ -9,7 +28,45 @@ pub fn copy_to_clipboard(text: &str) -> Result<()> {
         .context("failed to copy text to clipboard")
 }
 
-pub fn send_to_terminal(command: &str) -> Result<()> {
+/// Delivers `command` to a terminal per `target`: `CurrentPane` injects into
+/// the multiplexer pane recaller is already running in (tmux/screen),
+/// `NewWindow` always spawns a fresh GUI emulator, and `Auto` picks
+/// `CurrentPane` when `$TMUX` or `$STY` is set (we're inside a multiplexer,
+/// where spawning a GUI window would fail or land somewhere else entirely)
+/// and `NewWindow` otherwise.
+pub fn send_to_terminal(command: &str, target: TerminalTarget) -> Result<()> {
+    match target {
+        TerminalTarget::CurrentPane => inject_current_pane(command),
+        TerminalTarget::NewWindow => send_to_new_window(command),
+        TerminalTarget::Auto => {
+            if std::env::var_os("TMUX").is_some() || std::env::var_os("STY").is_some() {
+                inject_current_pane(command)
+            } else {
+                send_to_new_window(command)
+            }
+        }
+    }
+}
+
+/// Injects `command` into the current tmux pane or screen window without a
+/// trailing Enter, so the user can glance at/edit it before running it.
+fn inject_current_pane(command: &str) -> Result<()> {
+    if std::env::var_os("TMUX").is_some() {
+        run_command(&["tmux", "send-keys", "-l", "--", command], None, &[])?;
+        return Ok(());
+    }
+
+    if std::env::var_os("STY").is_some() {
+        run_command(&["screen", "-X", "stuff", command], None, &[])?;
+        return Ok(());
+    }
+
+    Err(anyhow!(
+        "not running inside tmux or screen; no current pane to inject into"
+    ))
+}
+
+fn send_to_new_window(command: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
         return send_to_terminal_macos(command);
@@ -43,12 +100,7 @@ end tell"#,
         cmd = escaped
     );
 
-    let status = Command::new("osascript")
-        .args(["-e", &script])
-        .status()
-        .context("failed to invoke osascript")?;
-
-    if status.success() {
+    if run_command(&["osascript", "-e", &script], None, &[]).is_ok() {
         return Ok(());
     }
 
@@ -63,17 +115,26 @@ end tell"#,
         cmd = escaped
     );
 
-    Command::new("osascript")
-        .args(["-e", &iterm_script])
-        .status()
-        .context("failed to invoke osascript for iTerm")?
-        .success()
-        .then_some(())
-        .ok_or_else(|| anyhow!("failed to send command to Terminal or iTerm"))
+    run_command(&["osascript", "-e", &iterm_script], None, &[])
+        .map(|_| ())
+        .context("failed to send command to Terminal or iTerm")
 }
 
 #[cfg(target_os = "linux")]
 fn send_to_terminal_linux(command: &str) -> Result<()> {
+    // wezterm's CLI types text into a pane rather than launching a new
+    // window via `bash -lc`, so it can't share the generic table below.
+    if which::which("wezterm").is_ok()
+        && run_command(
+            &["wezterm", "cli", "send-text", "--no-paste", command],
+            None,
+            &[],
+        )
+        .is_ok()
+    {
+        return Ok(());
+    }
+
     let wrapped = if command.trim_end().ends_with("exec bash") {
         command.to_string()
     } else {
@@ -93,13 +154,10 @@ fn send_to_terminal_linux(command: &str) -> Result<()> {
 
     for (term, args) in terminals {
         if which::which(term).is_ok() {
-            let mut cmd = Command::new(term);
-            for arg in *args {
-                cmd.arg(arg);
-            }
-            cmd.arg(&wrapped);
-            cmd.spawn()
-                .with_context(|| format!("failed to launch {term}"))?;
+            let mut argv: Vec<&str> = vec![term];
+            argv.extend_from_slice(args);
+            argv.push(&wrapped);
+            spawn_detached(&argv, None, &[])?;
             return Ok(());
         }
     }
@@ -110,19 +168,13 @@ fn send_to_terminal_linux(command: &str) -> Result<()> {
 pub fn open_path(path: &str) -> Result<()> {
     #[cfg(target_os = "macos")]
     {
-        Command::new("open")
-            .arg(path)
-            .spawn()
-            .context("failed to spawn open")?;
+        run_command(&["open", path], None, &[])?;
         return Ok(());
     }
 
     #[cfg(target_os = "linux")]
     {
-        Command::new("xdg-open")
-            .arg(path)
-            .spawn()
-            .context("failed to spawn xdg-open")?;
+        run_command(&["xdg-open", path], None, &[])?;
         return Ok(());
     }
 
@@ -131,3 +183,33 @@ pub fn open_path(path: &str) -> Result<()> {
         Err(anyhow!("opening files is not supported on this platform"))
     }
 }
+
+/// Moves `path` to the OS trash/recycle bin instead of deleting it outright.
+pub fn move_to_trash(path: &str) -> Result<()> {
+    trash::delete(path).with_context(|| format!("failed to move {path} to trash"))
+}
+
+/// Reveals `path` in the platform's file manager, selecting it if the file
+/// manager supports that; otherwise just opens its parent directory.
+pub fn reveal_path(path: &str) -> Result<()> {
+    #[cfg(target_os = "macos")]
+    {
+        run_command(&["open", "-R", path], None, &[])?;
+        return Ok(());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let parent = std::path::Path::new(path)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+        run_command(&["xdg-open", &parent], None, &[])?;
+        return Ok(());
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    {
+        Err(anyhow!("revealing files is not supported on this platform"))
+    }
+}
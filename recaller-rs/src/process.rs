@@ -0,0 +1,100 @@
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output};
+
+use anyhow::{Context, Result, anyhow};
+
+const MAX_STDERR_LINES: usize = 5;
+
+/// Runs `argv[0]` with `argv[1..]` and waits for it to exit. On a non-zero
+/// exit status the error carries the full joined argv, the working
+/// directory, the exit code, and the first few lines of stderr, instead of
+/// collapsing into a bare "command failed" the caller can't act on.
+pub fn run_command(argv: &[&str], cwd: Option<&Path>, env: &[(&str, &str)]) -> Result<Output> {
+    let (program, args) = argv
+        .split_first()
+        .ok_or_else(|| anyhow!("empty command"))?;
+
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("failed to spawn `{}`", argv.join(" ")))?;
+
+    if !output.status.success() {
+        return Err(anyhow!(format_command_error(
+            argv,
+            cwd,
+            output.status,
+            &output.stderr
+        )));
+    }
+
+    Ok(output)
+}
+
+/// Spawns `argv[0]` without waiting for it to exit, for GUI terminal
+/// emulators and other long-running processes recaller hands a command off
+/// to rather than runs itself.
+pub fn spawn_detached(argv: &[&str], cwd: Option<&Path>, env: &[(&str, &str)]) -> Result<Child> {
+    let (program, args) = argv
+        .split_first()
+        .ok_or_else(|| anyhow!("empty command"))?;
+
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if let Some(dir) = cwd {
+        cmd.current_dir(dir);
+    }
+    for (key, value) in env {
+        cmd.env(key, value);
+    }
+
+    cmd.spawn()
+        .with_context(|| format!("failed to spawn `{}`", argv.join(" ")))
+}
+
+/// Spawns `argv[0]` with inherited stdio and waits for it to exit, for
+/// interactive programs like `$EDITOR` that need the terminal themselves
+/// rather than having their output captured.
+pub fn run_interactive(argv: &[&str]) -> Result<ExitStatus> {
+    let (program, args) = argv
+        .split_first()
+        .ok_or_else(|| anyhow!("empty command"))?;
+
+    Command::new(program)
+        .args(args)
+        .status()
+        .with_context(|| format!("failed to spawn `{}`", argv.join(" ")))
+}
+
+/// Builds a diagnostic error string: the joined argv, the working directory
+/// (if any), the exit status, and the first `MAX_STDERR_LINES` lines of
+/// stderr.
+pub fn format_command_error(
+    argv: &[&str],
+    cwd: Option<&Path>,
+    status: ExitStatus,
+    stderr: &[u8],
+) -> String {
+    let mut message = format!("command `{}` exited with {status}", argv.join(" "));
+
+    if let Some(dir) = cwd {
+        message.push_str(&format!(" (cwd: {})", dir.display()));
+    }
+
+    let stderr_text = String::from_utf8_lossy(stderr);
+    let stderr_head: Vec<&str> = stderr_text.lines().take(MAX_STDERR_LINES).collect();
+    if !stderr_head.is_empty() {
+        message.push_str("\nstderr:\n");
+        message.push_str(&stderr_head.join("\n"));
+    }
+
+    message
+}
@@ -22,7 +22,9 @@ impl AppState {
 
     pub fn history_index(&mut self) -> Result<&HistoryIndex> {
         if self.history_index.is_none() {
-            let index = load_history_index().map_err(|err| anyhow!(err))?;
+            let source_override = self.config.history.source_override.as_deref();
+            let index = load_history_index(source_override, &self.config.history.redaction)
+                .map_err(|err| anyhow!(err))?;
             self.history_index = Some(index);
         }
 
@@ -34,7 +36,7 @@ impl AppState {
 
     pub fn help_manager(&mut self) -> Arc<HelpManager> {
         if self.help_manager.is_none() {
-            self.help_manager = Some(Arc::new(HelpManager::new()));
+            self.help_manager = Some(Arc::new(HelpManager::new(&self.config.help)));
         }
         self.help_manager
             .as_ref()
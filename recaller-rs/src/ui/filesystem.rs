@@ -1,4 +1,8 @@
+use std::fs;
 use std::io::stdout;
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::OnceLock;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
@@ -12,34 +16,24 @@ use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 
 use crate::config::Config;
+use crate::fs::mounts::{self, MountInfo};
+use crate::fs::watcher::{self, WatchEvent};
 use crate::fs::{FilesystemIndexer, RankedFile};
-use crate::platform::{copy_to_clipboard, open_path};
+use crate::keymap::{Action, Keymap};
+use crate::platform::{copy_to_clipboard, move_to_trash, open_path, reveal_path};
 use crate::state::AppState;
 
 const FILTER_MODES: [&str; 3] = ["All", "Dirs", "Files"];
 const FILTER_ICONS: [&str; 3] = ["📁📄", "📁", "📄"];
 const STATUS_TIMEOUT: Duration = Duration::from_secs(4);
-const HELP_TEXT: &[&str] = &[
-    "Filesystem Shortcuts",
-    "",
-    "General:",
-    "  Esc / Ctrl+C  - Exit UI",
-    "  Ctrl+H        - Toggle this help window",
-    "",
-    "Search Pane:",
-    "  Typing        - Filter indexed files",
-    "  Backspace     - Delete character",
-    "  Tab           - Toggle metadata focus",
-    "  Up/Down       - Navigate results (or metadata when focused)",
-    "",
-    "Actions:",
-    "  Enter         - Open file/directory",
-    "  Ctrl+Y        - Copy selected path",
-    "  Ctrl+T        - Cycle filter (All/Dirs/Files)",
-];
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum FilterMode {
@@ -48,6 +42,36 @@ enum FilterMode {
     Files = 2,
 }
 
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum View {
+    Files,
+    Mounts,
+}
+
+/// A search request tagged with a monotonically increasing generation so the
+/// UI thread can tell stale worker responses apart from the latest one.
+struct SearchQuery {
+    input: String,
+    enable_fuzzy: bool,
+    generation: u64,
+}
+
+enum IndexerRequest {
+    Search(SearchQuery),
+    RecordOpen { path: String },
+    RemovePath { path: String },
+    WatchBatch(Vec<WatchEvent>),
+}
+
+enum IndexerResponse {
+    Results {
+        ranked: Vec<RankedFile>,
+        generation: u64,
+    },
+}
+
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(40);
+
 pub fn run(state: &mut AppState, indexer: &mut FilesystemIndexer) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = stdout();
@@ -56,10 +80,84 @@ pub fn run(state: &mut AppState, indexer: &mut FilesystemIndexer) -> Result<()>
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
 
-    let mut ui_state = FilesystemUiState::new(state.config.history.enable_fuzzing);
-    ui_state.refresh_results(indexer)?;
+    let mut ui_state = FilesystemUiState::new(
+        state.config.history.enable_fuzzing,
+        state.config.filesystem.preview_byte_budget,
+        state.config.filesystem.preview_tab_width,
+        Keymap::from_config(&state.config.filesystem.keymap),
+        state.config.filesystem.trash_enabled,
+    );
+    let quiet = state.config.quiet;
+    let config = &state.config;
+    let watch_roots = indexer.get_root_paths();
+    let ignore_patterns = state.config.filesystem.ignore_patterns.clone();
+
+    let result = std::thread::scope(|scope| {
+        let (req_tx, req_rx) = mpsc::channel::<IndexerRequest>();
+        let (resp_tx, resp_rx) = mpsc::channel::<IndexerResponse>();
+        let (watch_tx, watch_rx) = mpsc::channel::<Vec<WatchEvent>>();
+
+        // Keeping the live `notify` watcher alive for the scope's duration is
+        // what keeps watching active; it's torn down (and its debounce
+        // thread with it) when the scope exits.
+        let _watcher = match watcher::spawn(&watch_roots, ignore_patterns, watch_tx) {
+            Ok(watcher) => Some(watcher),
+            Err(err) => {
+                if !quiet {
+                    eprintln!("⚠️  Live index updates disabled: {err}");
+                }
+                None
+            }
+        };
+
+        let forward_tx = req_tx.clone();
+        scope.spawn(move || {
+            while let Ok(batch) = watch_rx.recv() {
+                if forward_tx.send(IndexerRequest::WatchBatch(batch)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        // The indexer lives exclusively on this worker thread for the
+        // lifetime of the UI session, so a slow search over a large index
+        // never stalls the 50ms draw loop.
+        scope.spawn(move || {
+            while let Ok(request) = req_rx.recv() {
+                match request {
+                    IndexerRequest::Search(query) => {
+                        let ranked = indexer.search_files(&query.input, query.enable_fuzzy);
+                        let _ = resp_tx.send(IndexerResponse::Results {
+                            ranked,
+                            generation: query.generation,
+                        });
+                    }
+                    IndexerRequest::RecordOpen { path } => {
+                        indexer.add_path(&path, Some(Utc::now()), true);
+                        let _ = indexer.persist_index(!quiet);
+                    }
+                    IndexerRequest::RemovePath { path } => {
+                        indexer.remove_path(&path);
+                        let _ = indexer.persist_index(!quiet);
+                    }
+                    IndexerRequest::WatchBatch(events) => {
+                        for event in events {
+                            match event {
+                                WatchEvent::Upsert(path) => indexer.add_path(&path, None, false),
+                                WatchEvent::Remove(path) => {
+                                    indexer.remove_path(&path);
+                                }
+                            }
+                        }
+                        let _ = indexer.persist_index(!quiet);
+                    }
+                }
+            }
+        });
 
-    let result = run_loop(&mut terminal, &mut ui_state, &state.config, indexer);
+        ui_state.dispatch_search(&req_tx);
+        run_loop(&mut terminal, &mut ui_state, config, &req_tx, &resp_rx)
+    });
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -72,7 +170,8 @@ fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     ui_state: &mut FilesystemUiState,
     state: &Config,
-    indexer: &mut FilesystemIndexer,
+    req_tx: &mpsc::Sender<IndexerRequest>,
+    resp_rx: &mpsc::Receiver<IndexerResponse>,
 ) -> Result<()> {
     let mut status = String::new();
     let mut status_time = Instant::now();
@@ -94,6 +193,32 @@ fn run_loop(
             let input = Paragraph::new(ui_state.input.clone()).block(input_block);
             f.render_widget(input, layout[0]);
 
+            if ui_state.view == View::Mounts {
+                render_mounts(f, layout[1], ui_state);
+
+                if status_time.elapsed() > STATUS_TIMEOUT {
+                    status.clear();
+                }
+                let footer = Paragraph::new(if status.is_empty() {
+                    if ui_state.searching {
+                        "⏳ Searching…".to_string()
+                    } else {
+                        ui_state.keymap.footer_hint(&[
+                            Action::Open,
+                            Action::ScrollUp,
+                            Action::ToggleMounts,
+                            Action::Quit,
+                        ])
+                    }
+                } else {
+                    status.clone()
+                })
+                .wrap(Wrap { trim: true })
+                .block(Block::default().borders(Borders::ALL));
+                f.render_widget(footer, layout[2]);
+                return;
+            }
+
             let body = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
@@ -136,8 +261,8 @@ fn run_loop(
                 } else {
                     Style::default()
                 })
-                .title("Metadata");
-            let meta_text = Paragraph::new(ui_state.metadata_text())
+                .title("Preview");
+            let meta_text = Paragraph::new(Text::from(ui_state.preview_lines()))
                 .block(meta_block)
                 .wrap(Wrap { trim: true });
             f.render_widget(meta_text, body[1]);
@@ -146,7 +271,26 @@ fn run_loop(
                 status.clear();
             }
             let footer = Paragraph::new(if status.is_empty() {
-                "Enter: open  Ctrl+Y: copy path  Ctrl+T: toggle filter  Ctrl+H: help  Tab: focus metadata  Esc: quit".into()
+                if ui_state.searching {
+                    "⏳ Searching…".to_string()
+                } else {
+                    {
+                        let mut actions = vec![
+                            Action::Open,
+                            Action::CopyPath,
+                            Action::CycleFilter,
+                            Action::ToggleMounts,
+                        ];
+                        if ui_state.trash_enabled {
+                            actions.push(Action::Trash);
+                        }
+                        actions.push(Action::Reveal);
+                        actions.push(Action::ToggleHelp);
+                        actions.push(Action::FocusMetadata);
+                        actions.push(Action::Quit);
+                        ui_state.keymap.footer_hint(&actions)
+                    }
+                }
             } else {
                 status.clone()
             })
@@ -157,7 +301,7 @@ fn run_loop(
             if ui_state.show_help {
                 let area = centered_rect(70, 70, f.size());
                 f.render_widget(Clear, area);
-                let help_text = HELP_TEXT.join("\n");
+                let help_text = ui_state.help_modal_text();
                 let help = Paragraph::new(help_text)
                     .block(
                         Block::default()
@@ -169,12 +313,28 @@ fn run_loop(
                     .scroll((ui_state.help_scroll, 0));
                 f.render_widget(help, area);
             }
+
+            if let Some(path) = ui_state.confirming_trash.as_ref() {
+                let area = centered_rect(60, 20, f.size());
+                f.render_widget(Clear, area);
+                let confirm = Paragraph::new(format!(
+                    "Move to trash?\n\n{path}\n\n[y] Confirm   [n] Cancel"
+                ))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Confirm Trash")
+                        .border_style(Style::default().fg(Color::Red)),
+                )
+                .wrap(Wrap { trim: true });
+                f.render_widget(confirm, area);
+            }
         })?;
 
         if event::poll(Duration::from_millis(50))? {
             match event::read()? {
                 Event::Key(key) => {
-                    if handle_key(key, ui_state, indexer, &mut status, &mut status_time, state)? {
+                    if handle_key(key, ui_state, req_tx, &mut status, &mut status_time, state)? {
                         return Ok(());
                     }
                 }
@@ -182,17 +342,50 @@ fn run_loop(
                 _ => {}
             }
         }
+
+        if ui_state.input_dirty && ui_state.last_edit.elapsed() >= SEARCH_DEBOUNCE {
+            ui_state.input_dirty = false;
+            ui_state.dispatch_search(req_tx);
+        }
+
+        while let Ok(IndexerResponse::Results { ranked, generation }) = resp_rx.try_recv() {
+            if generation == ui_state.generation {
+                ui_state.apply_results(ranked);
+            }
+        }
     }
 }
 
 fn handle_key(
     key: KeyEvent,
     state: &mut FilesystemUiState,
-    indexer: &mut FilesystemIndexer,
+    req_tx: &mpsc::Sender<IndexerRequest>,
     status: &mut String,
     status_time: &mut Instant,
-    config: &Config,
+    _config: &Config,
 ) -> Result<bool> {
+    if let Some(path) = state.confirming_trash.clone() {
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                state.confirming_trash = None;
+                match move_to_trash(&path) {
+                    Ok(()) => {
+                        let _ = req_tx.send(IndexerRequest::RemovePath { path: path.clone() });
+                        state.remove_trashed_path(&path);
+                        *status = format!("🗑️  Trashed: {path}");
+                    }
+                    Err(err) => {
+                        *status = format!("❌ Failed to trash {path}: {err}");
+                    }
+                }
+                *status_time = Instant::now();
+            }
+            KeyCode::Char('n') | KeyCode::Esc => state.cancel_trash_confirmation(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
     if state.show_help {
         match key.code {
             KeyCode::Esc => state.hide_help_modal(),
@@ -206,59 +399,106 @@ fn handle_key(
         return Ok(false);
     }
 
-    match key.code {
-        KeyCode::Esc => return Ok(true),
-        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
-        KeyCode::Char('h') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            state.show_help_modal();
+    let action = state.keymap.resolve(key.code, key.modifiers);
+
+    if state.view == View::Mounts {
+        match action {
+            Some(Action::Quit) => return Ok(true),
+            Some(Action::ToggleMounts) => state.view = View::Files,
+            Some(Action::ScrollUp) => state.move_mount_selection_up(),
+            Some(Action::ScrollDown) => state.move_mount_selection_down(),
+            Some(Action::Open) => {
+                if let Some(mount) = state.current_mount() {
+                    state.input = mount.mount_point.clone();
+                    state.view = View::Files;
+                    state.dispatch_search(req_tx);
+                }
+            }
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    if let Some(action) = action {
+        match action {
+            Action::Quit => return Ok(true),
+            Action::ToggleHelp => state.show_help_modal(),
+            Action::ToggleMounts => state.show_mounts_view(),
+            Action::FocusMetadata => state.focus_metadata = !state.focus_metadata,
+            Action::ScrollUp => {
+                if state.focus_metadata {
+                    state.scroll_metadata_up();
+                } else {
+                    state.move_selection_up();
+                }
+            }
+            Action::ScrollDown => {
+                if state.focus_metadata {
+                    state.scroll_metadata_down();
+                } else {
+                    state.move_selection_down();
+                }
+            }
+            Action::Open => {
+                if let Some(file) = state.current_file() {
+                    open_path(&file.path)?;
+                    let _ = req_tx.send(IndexerRequest::RecordOpen {
+                        path: file.path.clone(),
+                    });
+                    println!("\n🚀 Opened: {}", file.path);
+                    return Ok(true);
+                }
+            }
+            Action::CopyPath => {
+                if let Some(file) = state.current_file() {
+                    copy_to_clipboard(&file.path)?;
+                    *status = format!("📋 Copied path: {}", file.path);
+                    *status_time = Instant::now();
+                }
+            }
+            Action::CycleFilter => {
+                state.cycle_filter();
+                state.apply_filter();
+            }
+            Action::Trash => {
+                if state.trash_enabled {
+                    state.request_trash_confirmation();
+                } else {
+                    *status = "🚫 Trash is disabled (filesystem.trash_enabled: false)".to_string();
+                    *status_time = Instant::now();
+                }
+            }
+            Action::Reveal => {
+                if let Some(file) = state.current_file() {
+                    let path = file.path.clone();
+                    match reveal_path(&path) {
+                        Ok(()) => {
+                            *status = format!("📂 Revealed: {path}");
+                        }
+                        Err(err) => {
+                            *status = format!("❌ Failed to reveal {path}: {err}");
+                        }
+                    }
+                    *status_time = Instant::now();
+                }
+            }
         }
+        return Ok(false);
+    }
+
+    match key.code {
         KeyCode::Char(ch) if key.modifiers.is_empty() => {
             if !state.focus_metadata {
                 state.input.push(ch);
-                state.refresh_results(indexer)?;
+                state.mark_input_dirty();
             }
         }
         KeyCode::Backspace => {
             if !state.focus_metadata {
                 state.input.pop();
-                state.refresh_results(indexer)?;
-            }
-        }
-        KeyCode::Tab => state.focus_metadata = !state.focus_metadata,
-        KeyCode::Up => {
-            if state.focus_metadata {
-                state.scroll_metadata_up();
-            } else {
-                state.move_selection_up();
-            }
-        }
-        KeyCode::Down => {
-            if state.focus_metadata {
-                state.scroll_metadata_down();
-            } else {
-                state.move_selection_down();
-            }
-        }
-        KeyCode::Enter => {
-            if let Some(file) = state.current_file() {
-                open_path(&file.path)?;
-                indexer.add_path(&file.path, Some(Utc::now()), true);
-                indexer.persist_index(!config.quiet)?;
-                println!("\n🚀 Opened: {}", file.path);
-                return Ok(true);
-            }
-        }
-        KeyCode::Char('y') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            if let Some(file) = state.current_file() {
-                copy_to_clipboard(&file.path)?;
-                *status = format!("📋 Copied path: {}", file.path);
-                *status_time = Instant::now();
+                state.mark_input_dirty();
             }
         }
-        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            state.cycle_filter();
-            state.refresh_results(indexer)?;
-        }
         _ => {}
     }
     Ok(false)
@@ -276,10 +516,29 @@ struct FilesystemUiState {
     metadata_scroll: usize,
     show_help: bool,
     help_scroll: u16,
+    view: View,
+    mounts: Vec<MountInfo>,
+    mounts_selected: usize,
+    mounts_list_state: ListState,
+    preview_byte_budget: usize,
+    preview_tab_width: usize,
+    generation: u64,
+    input_dirty: bool,
+    last_edit: Instant,
+    searching: bool,
+    keymap: Keymap,
+    trash_enabled: bool,
+    confirming_trash: Option<String>,
 }
 
 impl FilesystemUiState {
-    fn new(enable_fuzzy: bool) -> Self {
+    fn new(
+        enable_fuzzy: bool,
+        preview_byte_budget: usize,
+        preview_tab_width: usize,
+        keymap: Keymap,
+        trash_enabled: bool,
+    ) -> Self {
         let mut list_state = ListState::default();
         list_state.select(Some(0));
         Self {
@@ -294,11 +553,54 @@ impl FilesystemUiState {
             metadata_scroll: 0,
             show_help: false,
             help_scroll: 0,
+            view: View::Files,
+            mounts: Vec::new(),
+            mounts_selected: 0,
+            mounts_list_state: ListState::default(),
+            preview_byte_budget,
+            preview_tab_width,
+            generation: 0,
+            input_dirty: false,
+            last_edit: Instant::now(),
+            searching: false,
+            keymap,
+            trash_enabled,
+            confirming_trash: None,
         }
     }
 
-    fn refresh_results(&mut self, indexer: &FilesystemIndexer) -> Result<()> {
-        self.results = indexer.search_files(&self.input, self.enable_fuzzy);
+    /// Marks the search input as changed; the actual query is dispatched
+    /// from `run_loop` once `SEARCH_DEBOUNCE` has elapsed with no further
+    /// keystrokes, so rapid typing issues a single search.
+    fn mark_input_dirty(&mut self) {
+        self.input_dirty = true;
+        self.last_edit = Instant::now();
+    }
+
+    /// Sends the current input to the background indexer worker, tagged
+    /// with a freshly bumped generation.
+    fn dispatch_search(&mut self, req_tx: &mpsc::Sender<IndexerRequest>) {
+        self.generation += 1;
+        self.searching = true;
+        let _ = req_tx.send(IndexerRequest::Search(SearchQuery {
+            input: self.input.clone(),
+            enable_fuzzy: self.enable_fuzzy,
+            generation: self.generation,
+        }));
+    }
+
+    /// Applies a worker response that matches the latest requested
+    /// generation; callers are expected to have already dropped stale ones.
+    fn apply_results(&mut self, ranked: Vec<RankedFile>) {
+        self.results = ranked;
+        self.searching = false;
+        self.apply_filter();
+    }
+
+    /// Recomputes `filtered_results` from the cached `results` according to
+    /// `filter_mode`. Cheap and synchronous — filtering never needs a round
+    /// trip to the indexer worker.
+    fn apply_filter(&mut self) {
         self.filtered_results = self
             .results
             .iter()
@@ -320,7 +622,6 @@ impl FilesystemUiState {
             self.list_state.select(Some(self.selected));
         }
         self.metadata_scroll = 0;
-        Ok(())
     }
 
     fn current_file(&self) -> Option<&RankedFile> {
@@ -401,6 +702,27 @@ impl FilesystemUiState {
         }
     }
 
+    /// Renders the right-hand pane: a syntax-highlighted content preview for
+    /// regular files when one is readable and not binary, otherwise the
+    /// existing metadata block. Respects `metadata_scroll` either way.
+    fn preview_lines(&self) -> Vec<Line<'static>> {
+        if let Some(file) = self.current_file() {
+            if !file.metadata.is_directory {
+                if let Some(lines) =
+                    highlight_preview(&file.path, self.preview_byte_budget, self.preview_tab_width)
+                {
+                    let max_scroll = lines.len().saturating_sub(1);
+                    let start = self.metadata_scroll.min(max_scroll);
+                    return lines[start..].to_vec();
+                }
+            }
+        }
+        self.metadata_text()
+            .lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect()
+    }
+
     fn cycle_filter(&mut self) {
         self.filter_mode = match self.filter_mode {
             FilterMode::All => FilterMode::Dirs,
@@ -416,7 +738,7 @@ impl FilesystemUiState {
     }
 
     fn scroll_help_down(&mut self) {
-        let max_scroll = HELP_TEXT.len().saturating_sub(1) as u16;
+        let max_scroll = self.help_modal_text().lines().count().saturating_sub(1) as u16;
         if self.help_scroll < max_scroll {
             self.help_scroll += 1;
         }
@@ -431,6 +753,174 @@ impl FilesystemUiState {
         self.show_help = false;
         self.help_scroll = 0;
     }
+
+    /// Builds the help modal text from the active keymap, so it can never
+    /// drift from what's actually bound.
+    fn help_modal_text(&self) -> String {
+        let mut lines = vec![
+            "Filesystem Shortcuts".to_string(),
+            String::new(),
+            "Search Pane:".to_string(),
+            "  Typing        - Filter indexed files".to_string(),
+            "  Backspace     - Delete character".to_string(),
+            String::new(),
+            "Actions:".to_string(),
+        ];
+        lines.extend(self.keymap.help_lines());
+        lines.join("\n")
+    }
+
+    /// Opens the trash confirmation modal for the currently selected file,
+    /// a no-op if trashing is disabled in config or nothing is selected.
+    fn request_trash_confirmation(&mut self) {
+        if !self.trash_enabled {
+            return;
+        }
+        if let Some(file) = self.current_file() {
+            self.confirming_trash = Some(file.path.clone());
+        }
+    }
+
+    fn cancel_trash_confirmation(&mut self) {
+        self.confirming_trash = None;
+    }
+
+    /// Removes the just-trashed path from the cached results so the list
+    /// reflects the deletion immediately, without waiting on a re-search.
+    fn remove_trashed_path(&mut self, path: &str) {
+        self.results.retain(|file| file.path != path);
+        self.apply_filter();
+    }
+
+    fn show_mounts_view(&mut self) {
+        self.mounts = mounts::list_mounts();
+        self.mounts_selected = 0;
+        self.mounts_list_state
+            .select(if self.mounts.is_empty() { None } else { Some(0) });
+        self.view = View::Mounts;
+    }
+
+    fn current_mount(&self) -> Option<&MountInfo> {
+        self.mounts.get(self.mounts_selected)
+    }
+
+    fn move_mount_selection_up(&mut self) {
+        if self.mounts_selected > 0 {
+            self.mounts_selected -= 1;
+            self.mounts_list_state.select(Some(self.mounts_selected));
+        }
+    }
+
+    fn move_mount_selection_down(&mut self) {
+        if self.mounts_selected + 1 < self.mounts.len() {
+            self.mounts_selected += 1;
+            self.mounts_list_state.select(Some(self.mounts_selected));
+        }
+    }
+}
+
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines)
+}
+
+fn preview_theme() -> &'static syntect::highlighting::Theme {
+    static THEME: OnceLock<syntect::highlighting::Theme> = OnceLock::new();
+    THEME.get_or_init(|| {
+        let mut themes = ThemeSet::load_defaults().themes;
+        themes
+            .remove("base16-ocean.dark")
+            .or_else(|| themes.remove("InspiredGitHub"))
+            .expect("syntect bundles base16-ocean.dark and InspiredGitHub by default")
+    })
+}
+
+/// Reads up to `byte_budget` bytes of `path` and renders them as
+/// syntax-highlighted `Line`s via `syntect`. Returns `None` for directories,
+/// unreadable paths, or content that looks binary (contains a NUL byte).
+fn highlight_preview(path: &str, byte_budget: usize, tab_width: usize) -> Option<Vec<Line<'static>>> {
+    let data = fs::read(path).ok()?;
+    if data.is_empty() {
+        return None;
+    }
+    let sample_len = data.len().min(byte_budget);
+    let sample = &data[..sample_len];
+    if sample.contains(&0) {
+        return None;
+    }
+
+    let tab_replacement = " ".repeat(tab_width.max(1));
+    let text = String::from_utf8_lossy(sample).replace('\t', &tab_replacement);
+
+    let syntax_set = syntax_set();
+    let syntax = Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| syntax_set.find_syntax_by_extension(ext))
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+    let mut highlighter = HighlightLines::new(syntax, preview_theme());
+    let mut lines = Vec::new();
+    for line in LinesWithEndings::from(&text) {
+        let ranges = highlighter.highlight_line(line, syntax_set).ok()?;
+        let spans: Vec<Span<'static>> = ranges
+            .into_iter()
+            .map(|(style, text)| {
+                let color = Color::Rgb(
+                    style.foreground.r,
+                    style.foreground.g,
+                    style.foreground.b,
+                );
+                Span::styled(text.trim_end_matches('\n').to_string(), Style::default().fg(color))
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+    Some(lines)
+}
+
+fn render_mounts(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    ui_state: &mut FilesystemUiState,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" 💽 Mounted Filesystems ");
+
+    let items: Vec<ListItem> = if ui_state.mounts.is_empty() {
+        vec![ListItem::new("No mounted filesystems detected on this platform.")]
+    } else {
+        ui_state
+            .mounts
+            .iter()
+            .map(|mount| ListItem::new(format_mount_entry(mount)))
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .highlight_symbol("▶ ");
+    f.render_stateful_widget(list, area, &mut ui_state.mounts_list_state);
+}
+
+fn format_mount_entry(mount: &MountInfo) -> String {
+    let percent = mount.used_percent();
+    let filled = ((percent / 100.0) * 20.0).round() as usize;
+    let filled = filled.min(20);
+    let bar = format!("[{}{}]", "#".repeat(filled), "-".repeat(20 - filled));
+
+    format!(
+        "{} on {} ({})\n  {} {:.1}%  {} used / {} total",
+        mount.device,
+        mount.mount_point,
+        mount.fs_type,
+        bar,
+        percent,
+        human_size(mount.used_bytes),
+        human_size(mount.total_bytes)
+    )
 }
 
 fn format_file_entry(file: &RankedFile) -> String {
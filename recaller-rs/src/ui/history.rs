@@ -1,8 +1,8 @@
-use std::io::stdout;
+use std::fs::{File, OpenOptions};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
 use crossterm::execute;
 use crossterm::terminal::{
@@ -13,9 +13,14 @@ use ratatui::backend::CrosstermBackend;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{
+    Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+    ScrollbarState, Wrap,
+};
 
+use crate::config::TerminalTarget;
 use crate::help_system::parser::split_command;
+use crate::help_system::snippet::{self, Snippet};
 use crate::history::RankedCommand;
 use crate::platform::{copy_to_clipboard, send_to_terminal};
 use crate::state::AppState;
@@ -35,21 +40,43 @@ const HELP_TEXT: &[&str] = &[
     "  Home/End      - Jump to start/end",
     "  Ctrl+K/Ctrl+J - Jump to first/last result",
     "",
+    "Help Pane:",
+    "  Tab           - Focus/unfocus the Help pane",
+    "  Up/Down       - Scroll Help (PgUp/PgDn for 10 lines)",
+    "  Home/End      - Jump to top/bottom of Help",
+    "",
+    "Help Search Popup:",
+    "  Enter         - Commit query, then jump to next match",
+    "  n / N         - Next / previous match (after committing)",
+    "",
     "Actions:",
     "  Enter         - Print command and quit",
     "  Ctrl+E        - Send command to terminal",
     "  Ctrl+Y        - Copy command to clipboard",
+    "  Ctrl+R        - Extract a runnable snippet from Help",
+    "  Ctrl+W        - Toggle whole-word match mode",
 ];
 
-pub fn run(state: &mut AppState) -> Result<()> {
+/// Draws the picker to the controlling terminal rather than inherited
+/// stdout, and leaves stdout free for the command `handle_key_event` prints
+/// (between blank lines) on selection. This is what makes
+/// `selected=$(recaller run | sed '/^$/d' | tail -n1)` work as an
+/// fzf/navi-style widget (see `handle_init`): capturing stdout
+/// only grabs the chosen command, not alternate-screen escape sequences and
+/// redraws, which instead go to `/dev/tty` and never enter the pipe.
+pub fn run(state: &mut AppState, copy_on_select: bool) -> Result<()> {
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .context("failed to open /dev/tty for the interactive UI")?;
     enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen)?;
-    let backend = CrosstermBackend::new(stdout);
+    execute!(tty, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(tty);
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
 
-    let result = run_loop(state, &mut terminal);
+    let result = run_loop(state, &mut terminal, copy_on_select);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -60,9 +87,11 @@ pub fn run(state: &mut AppState) -> Result<()> {
 
 fn run_loop(
     state: &mut AppState,
-    terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
+    terminal: &mut Terminal<CrosstermBackend<File>>,
+    copy_on_select: bool,
 ) -> Result<()> {
     let mut ui_state = HistoryUiState::new(state.config.history.enable_fuzzing);
+    let terminal_target = state.config.terminal.target;
     ui_state.refresh_results(state)?;
 
     let help_manager = state.help_manager();
@@ -82,6 +111,27 @@ fn run_loop(
         }
     });
 
+    let (marker_tx, marker_rx) = mpsc::channel::<MarkerRequest>();
+    let (marker_resp_tx, marker_resp_rx) = mpsc::channel::<MarkerResponse>();
+
+    std::thread::spawn(move || {
+        while let Ok(request) = marker_rx.recv() {
+            let mut line_indices: Vec<usize> = find_help_matches(
+                &request.lines,
+                &request.query,
+                request.whole_word,
+            )
+            .into_iter()
+            .map(|(line_idx, _, _)| line_idx)
+            .collect();
+            line_indices.dedup();
+            let _ = marker_resp_tx.send(MarkerResponse {
+                line_indices,
+                generation: request.generation,
+            });
+        }
+    });
+
     let mut status = String::new();
     let mut status_time = Instant::now();
     if let Some(cmd) = ui_state.current_command() {
@@ -116,7 +166,12 @@ fn run_loop(
                 ui_state
                     .results
                     .iter()
-                    .map(|cmd| ListItem::new(cmd.command.clone()))
+                    .map(|cmd| {
+                        ListItem::new(Line::from(highlighted_match_spans(
+                            &cmd.command,
+                            &cmd.match_indices,
+                        )))
+                    })
                     .collect()
             };
 
@@ -130,18 +185,41 @@ fn run_loop(
                 .highlight_symbol("▶ ");
             f.render_stateful_widget(suggestions, body[0], &mut ui_state.list_state);
 
-            let help_block = Block::default().borders(Borders::ALL).title("Help");
+            ui_state.reflow_help_pane(body[1].width.saturating_sub(2));
+
+            let help_block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(if ui_state.help_focused {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                })
+                .title("Help");
             let help = Paragraph::new(ui_state.help_text_widget())
                 .block(help_block)
-                .wrap(Wrap { trim: true });
+                .wrap(Wrap { trim: true })
+                .scroll((ui_state.help_pane_scroll, 0));
             f.render_widget(help, body[1]);
 
+            let mut help_scrollbar_state = ScrollbarState::new(ui_state.help_pane_line_count)
+                .position(ui_state.help_pane_scroll as usize);
+            f.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None),
+                body[1],
+                &mut help_scrollbar_state,
+            );
+            render_help_markers(f, body[1], &ui_state.help_markers, ui_state.help_lines.len());
+
             if status_time.elapsed() > Duration::from_secs(4) {
                 status.clear();
             }
             let footer = Paragraph::new(if status.is_empty() {
-                "Enter: print  Ctrl+E: send to terminal  Ctrl+Y: copy  /: help search  Ctrl+H: help  Esc: quit"
-                    .to_string()
+                format!(
+                    "Enter: print  Ctrl+E: send to terminal  Ctrl+Y: copy  Tab: focus help  /: help search  Ctrl+W: whole-word [{}]  Ctrl+H: help  Esc: quit",
+                    if ui_state.whole_word { "on" } else { "off" }
+                )
             } else {
                 status.clone()
             })
@@ -185,12 +263,56 @@ fn run_loop(
                     .block(
                         Block::default()
                             .borders(Borders::ALL)
-                            .title("Help Matches"),
+                            .title(ui_state.help_search_title()),
                     )
                     .wrap(Wrap { trim: true })
                     .scroll((ui_state.help_search_scroll, 0));
                 f.render_widget(search_text, chunks[1]);
             }
+
+            if ui_state.show_snippets {
+                let area = centered_rect(70, 70, f.size());
+                f.render_widget(Clear, area);
+
+                if let Some(placeholder) = ui_state.current_placeholder() {
+                    let block = Block::default()
+                        .borders(Borders::ALL)
+                        .title("Fill Placeholder")
+                        .border_style(Style::default().fg(Color::Magenta));
+                    let text = format!(
+                        "{}\n\n{}",
+                        placeholder, ui_state.placeholder_input
+                    );
+                    f.render_widget(Paragraph::new(text).block(block).wrap(Wrap { trim: true }), area);
+                } else {
+                    let items: Vec<ListItem> = if ui_state.snippet_candidates.is_empty() {
+                        vec![ListItem::new("No runnable snippets found in Help")]
+                    } else {
+                        ui_state
+                            .snippet_candidates
+                            .iter()
+                            .map(|s| match &s.label {
+                                Some(label) => ListItem::new(format!("{}\n  {}", label, s.command)),
+                                None => ListItem::new(s.command.clone()),
+                            })
+                            .collect()
+                    };
+                    let list = List::new(items)
+                        .block(
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .title("Snippets (Enter: run, Esc: cancel)")
+                                .border_style(Style::default().fg(Color::Magenta)),
+                        )
+                        .highlight_style(
+                            Style::default()
+                                .fg(Color::Yellow)
+                                .add_modifier(Modifier::BOLD),
+                        )
+                        .highlight_symbol("▶ ");
+                    f.render_stateful_widget(list, area, &mut ui_state.snippet_list_state);
+                }
+            }
         })?;
 
         while let Ok((cmd, text)) = help_resp_rx.try_recv() {
@@ -201,9 +323,19 @@ fn run_loop(
             {
                 ui_state.set_help_text(text);
                 ui_state.pending_help = None;
+                ui_state.dispatch_markers(&marker_tx);
             }
         }
 
+        while let Ok(response) = marker_resp_rx.try_recv() {
+            ui_state.apply_marker_response(response);
+        }
+
+        if ui_state.markers_dirty {
+            ui_state.markers_dirty = false;
+            ui_state.dispatch_markers(&marker_tx);
+        }
+
         if ui_state.should_refresh() {
             ui_state.refresh_results(state)?;
             if let Some(cmd) = ui_state.current_command() {
@@ -217,7 +349,14 @@ fn run_loop(
         if event::poll(Duration::from_millis(50))? {
             match event::read()? {
                 Event::Key(key) => {
-                    if handle_key_event(key, &mut ui_state, &mut status, &mut status_time)? {
+                    if handle_key_event(
+                        key,
+                        &mut ui_state,
+                        &mut status,
+                        &mut status_time,
+                        copy_on_select,
+                        terminal_target,
+                    )? {
                         return Ok(());
                     }
                 }
@@ -233,6 +372,8 @@ fn handle_key_event(
     state: &mut HistoryUiState,
     status: &mut String,
     status_time: &mut Instant,
+    copy_on_select: bool,
+    terminal_target: TerminalTarget,
 ) -> Result<bool> {
     if state.show_help_modal {
         match key.code {
@@ -247,6 +388,33 @@ fn handle_key_event(
         return Ok(false);
     }
 
+    if state.show_snippets {
+        if state.assembling_snippet.is_some() {
+            match key.code {
+                KeyCode::Esc => {
+                    state.close_snippets();
+                    *status = "Snippet cancelled".into();
+                    *status_time = Instant::now();
+                }
+                KeyCode::Enter => state.commit_placeholder_value(),
+                KeyCode::Backspace => {
+                    state.placeholder_input.pop();
+                }
+                KeyCode::Char(ch) => state.placeholder_input.push(ch),
+                _ => {}
+            }
+        } else {
+            match key.code {
+                KeyCode::Esc => state.close_snippets(),
+                KeyCode::Up => state.move_snippet_selection_up(),
+                KeyCode::Down => state.move_snippet_selection_down(),
+                KeyCode::Enter => state.select_snippet(),
+                _ => {}
+            }
+        }
+        return Ok(false);
+    }
+
     if state.show_help_search {
         match key.code {
             KeyCode::Esc => {
@@ -254,11 +422,28 @@ fn handle_key_event(
                 *status = "Help search cancelled".into();
                 *status_time = Instant::now();
             }
+            KeyCode::Enter if state.help_search_committed => {
+                state.next_help_match();
+                *status = format!("Searching: {}", state.help_search_query);
+                *status_time = Instant::now();
+            }
             KeyCode::Enter => {
-                state.update_help_search_matches();
+                state.commit_help_search();
                 *status = format!("Searching: {}", state.help_search_query);
                 *status_time = Instant::now();
             }
+            KeyCode::Char('n') if state.help_search_committed => state.next_help_match(),
+            KeyCode::Char('N') if state.help_search_committed => state.prev_help_match(),
+            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                state.whole_word = !state.whole_word;
+                state.update_help_search_matches();
+                state.markers_dirty = true;
+                *status = format!(
+                    "🔤 Whole-word match: {}",
+                    if state.whole_word { "on" } else { "off" }
+                );
+                *status_time = Instant::now();
+            }
             KeyCode::Backspace => {
                 state.pop_help_search_char();
             }
@@ -290,10 +475,13 @@ fn handle_key_event(
         KeyCode::Char('h') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             state.open_help_modal();
         }
+        KeyCode::Tab => state.help_focused = !state.help_focused,
         KeyCode::Char(ch) => {
             if key.modifiers.is_empty() {
-                state.input.push(ch);
-                state.mark_dirty();
+                if !state.help_focused {
+                    state.input.push(ch);
+                    state.mark_dirty();
+                }
             } else if key.modifiers.contains(KeyModifiers::CONTROL) {
                 match ch {
                     'y' => {
@@ -305,29 +493,56 @@ fn handle_key_event(
                     }
                     'e' => {
                         if let Some(cmd) = state.current_command() {
-                            send_to_terminal(cmd)?;
+                            send_to_terminal(cmd, terminal_target)?;
                             *status = format!("🚀 Sent to terminal: {}", cmd);
                             *status_time = Instant::now();
                         }
                     }
+                    'r' => {
+                        state.open_snippets();
+                        *status = "Select a snippet to run".into();
+                        *status_time = Instant::now();
+                    }
+                    'w' => {
+                        state.whole_word = !state.whole_word;
+                        state.mark_dirty();
+                        state.update_help_search_matches();
+                        state.markers_dirty = true;
+                        *status = format!(
+                            "🔤 Whole-word match: {}",
+                            if state.whole_word { "on" } else { "off" }
+                        );
+                        *status_time = Instant::now();
+                    }
                     _ => {}
                 }
             }
         }
         KeyCode::Backspace => {
-            state.input.pop();
-            state.mark_dirty();
+            if !state.help_focused {
+                state.input.pop();
+                state.mark_dirty();
+            }
         }
         KeyCode::Enter => {
-            if let Some(cmd) = state.current_command() {
-                println!(
-                    "\n{}
-",
-                    cmd
-                );
-                return Ok(true);
+            if !state.help_focused {
+                if let Some(cmd) = state.current_command() {
+                    if copy_on_select {
+                        copy_to_clipboard(cmd)?;
+                        println!("\n📋 Copied: {}\n", cmd);
+                    } else {
+                        println!("\n{}\n", cmd);
+                    }
+                    return Ok(true);
+                }
             }
         }
+        KeyCode::Up if state.help_focused => state.scroll_help_pane_up(1),
+        KeyCode::Down if state.help_focused => state.scroll_help_pane_down(1),
+        KeyCode::PageUp if state.help_focused => state.scroll_help_pane_up(10),
+        KeyCode::PageDown if state.help_focused => state.scroll_help_pane_down(10),
+        KeyCode::Home if state.help_focused => state.scroll_help_pane_home(),
+        KeyCode::End if state.help_focused => state.scroll_help_pane_end(),
         KeyCode::Up => state.move_selection_up(),
         KeyCode::Down => state.move_selection_down(),
         KeyCode::Home => state.select_first(),
@@ -341,11 +556,16 @@ struct HistoryUiState {
     input: String,
     results: Vec<RankedCommand>,
     enable_fuzzing: bool,
+    whole_word: bool,
     selected: usize,
     pending_help: Option<String>,
     last_search: Instant,
     list_state: ListState,
     help_lines: Vec<String>,
+    styled_help_lines: Vec<Line<'static>>,
+    help_focused: bool,
+    help_pane_scroll: u16,
+    help_pane_line_count: usize,
     show_help_modal: bool,
     help_modal_scroll: u16,
     show_help_search: bool,
@@ -353,6 +573,19 @@ struct HistoryUiState {
     help_search_scroll: u16,
     help_search_highlights: Vec<(usize, usize, usize)>,
     help_search_manual_scroll: bool,
+    help_search_committed: bool,
+    current_match: usize,
+    help_markers: Vec<usize>,
+    marker_generation: u64,
+    markers_dirty: bool,
+    show_snippets: bool,
+    snippet_candidates: Vec<Snippet>,
+    snippet_list_state: ListState,
+    snippet_selected: usize,
+    pending_placeholders: Vec<String>,
+    filled_placeholders: Vec<(String, String)>,
+    placeholder_input: String,
+    assembling_snippet: Option<String>,
 }
 
 impl HistoryUiState {
@@ -363,11 +596,16 @@ impl HistoryUiState {
             input: String::new(),
             results: Vec::new(),
             enable_fuzzing,
+            whole_word: false,
             selected: 0,
             pending_help: None,
             last_search: Instant::now(),
             list_state,
             help_lines: vec!["Select a command to load help".into()],
+            styled_help_lines: vec![Line::raw("Select a command to load help")],
+            help_focused: false,
+            help_pane_scroll: 0,
+            help_pane_line_count: 1,
             show_help_modal: false,
             help_modal_scroll: 0,
             show_help_search: false,
@@ -375,12 +613,110 @@ impl HistoryUiState {
             help_search_scroll: 0,
             help_search_highlights: Vec::new(),
             help_search_manual_scroll: false,
+            help_search_committed: false,
+            current_match: 0,
+            help_markers: Vec::new(),
+            marker_generation: 0,
+            markers_dirty: false,
+            show_snippets: false,
+            snippet_candidates: Vec::new(),
+            snippet_list_state: ListState::default(),
+            snippet_selected: 0,
+            pending_placeholders: Vec::new(),
+            filled_placeholders: Vec::new(),
+            placeholder_input: String::new(),
+            assembling_snippet: None,
+        }
+    }
+
+    fn open_snippets(&mut self) {
+        self.snippet_candidates = snippet::extract_snippets(&self.help_lines.join("\n"));
+        self.snippet_selected = 0;
+        self.snippet_list_state.select(if self.snippet_candidates.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        self.assembling_snippet = None;
+        self.pending_placeholders.clear();
+        self.filled_placeholders.clear();
+        self.placeholder_input.clear();
+        self.show_snippets = true;
+    }
+
+    fn close_snippets(&mut self) {
+        self.show_snippets = false;
+        self.assembling_snippet = None;
+        self.pending_placeholders.clear();
+        self.filled_placeholders.clear();
+        self.placeholder_input.clear();
+    }
+
+    fn move_snippet_selection_up(&mut self) {
+        if self.snippet_selected > 0 {
+            self.snippet_selected -= 1;
+            self.snippet_list_state.select(Some(self.snippet_selected));
+        }
+    }
+
+    fn move_snippet_selection_down(&mut self) {
+        if self.snippet_selected + 1 < self.snippet_candidates.len() {
+            self.snippet_selected += 1;
+            self.snippet_list_state.select(Some(self.snippet_selected));
+        }
+    }
+
+    /// Selects the highlighted snippet, kicking off the placeholder prompt
+    /// sequence if it has any, or filling `input` immediately if not.
+    fn select_snippet(&mut self) {
+        let Some(chosen) = self.snippet_candidates.get(self.snippet_selected).cloned() else {
+            return;
+        };
+        self.pending_placeholders = snippet::extract_placeholders(&chosen.command);
+        self.filled_placeholders.clear();
+        self.placeholder_input.clear();
+        if self.pending_placeholders.is_empty() {
+            self.input = chosen.command;
+            self.mark_dirty();
+            self.close_snippets();
+        } else {
+            self.assembling_snippet = Some(chosen.command);
+        }
+    }
+
+    fn current_placeholder(&self) -> Option<&str> {
+        self.pending_placeholders.first().map(|s| s.as_str())
+    }
+
+    /// Commits the value typed for the current placeholder and either moves
+    /// on to the next one or assembles the final command.
+    fn commit_placeholder_value(&mut self) {
+        let Some(placeholder) = self.pending_placeholders.first().cloned() else {
+            return;
+        };
+        self.filled_placeholders
+            .push((placeholder, self.placeholder_input.clone()));
+        self.placeholder_input.clear();
+        self.pending_placeholders.remove(0);
+
+        if self.pending_placeholders.is_empty() {
+            if let Some(snippet) = self.assembling_snippet.take() {
+                self.input =
+                    snippet::substitute_placeholders(&snippet, &self.filled_placeholders);
+                self.mark_dirty();
+            }
+            self.close_snippets();
         }
     }
 
     fn refresh_results(&mut self, state: &mut AppState) -> Result<()> {
+        let frecency = state.config.history.frecency.clone();
         let index = state.history_index()?;
-        self.results = index.search(&self.input, self.enable_fuzzing);
+        let mut results = index.search(&self.input, self.enable_fuzzing, &frecency);
+        if self.whole_word && !self.input.is_empty() {
+            results.retain(|cmd| is_whole_word_match(&cmd.command, &cmd.match_indices));
+        }
+        self.results = results;
         if self.results.is_empty() {
             self.selected = 0;
             self.list_state.select(None);
@@ -395,16 +731,51 @@ impl HistoryUiState {
     }
 
     fn set_help_text(&mut self, text: String) {
-        self.help_lines = if text.is_empty() {
+        self.styled_help_lines = if crate::help_system::markdown::looks_like_markdown(&text) {
+            crate::help_system::markdown::parse_markdown(&text)
+        } else {
+            crate::help_system::ansi::parse_lines(&text)
+        };
+        let plain = crate::help_system::ansi::strip_ansi(&text);
+        self.help_lines = if plain.is_empty() {
             vec![String::new()]
         } else {
-            text.lines().map(|s| s.to_string()).collect()
+            plain.lines().map(|s| s.to_string()).collect()
         };
+        self.help_pane_scroll = 0;
         if !self.help_search_query.is_empty() {
             self.update_help_search_matches();
         }
     }
 
+    /// Recomputes how many reflowed (wrapped) lines the Help pane holds at
+    /// the given content width, so scroll bounds and the scrollbar thumb
+    /// stay accurate as the terminal is resized.
+    fn reflow_help_pane(&mut self, width: u16) {
+        self.help_pane_line_count = wrapped_line_count(&self.help_lines, width);
+        let max_scroll = self.help_pane_line_count.saturating_sub(1) as u16;
+        if self.help_pane_scroll > max_scroll {
+            self.help_pane_scroll = max_scroll;
+        }
+    }
+
+    fn scroll_help_pane_up(&mut self, amount: u16) {
+        self.help_pane_scroll = self.help_pane_scroll.saturating_sub(amount);
+    }
+
+    fn scroll_help_pane_down(&mut self, amount: u16) {
+        let max_scroll = self.help_pane_line_count.saturating_sub(1) as u16;
+        self.help_pane_scroll = (self.help_pane_scroll + amount).min(max_scroll);
+    }
+
+    fn scroll_help_pane_home(&mut self) {
+        self.help_pane_scroll = 0;
+    }
+
+    fn scroll_help_pane_end(&mut self) {
+        self.help_pane_scroll = self.help_pane_line_count.saturating_sub(1) as u16;
+    }
+
     fn mark_dirty(&mut self) {
         self.last_search = Instant::now() - SEARCH_DEBOUNCE - Duration::from_millis(1);
     }
@@ -450,6 +821,7 @@ impl HistoryUiState {
     fn open_help_search(&mut self) {
         self.show_help_search = true;
         self.help_search_manual_scroll = false;
+        self.help_search_committed = false;
         self.update_help_search_matches();
     }
 
@@ -457,74 +829,118 @@ impl HistoryUiState {
         self.show_help_search = false;
         self.help_search_scroll = 0;
         self.help_search_manual_scroll = false;
+        self.help_search_committed = false;
     }
 
     fn push_help_search_char(&mut self, ch: char) {
         self.help_search_query.push(ch);
         self.help_search_manual_scroll = false;
+        self.help_search_committed = false;
         self.update_help_search_matches();
+        self.markers_dirty = true;
     }
 
     fn pop_help_search_char(&mut self) {
         self.help_search_query.pop();
         self.help_search_manual_scroll = false;
+        self.help_search_committed = false;
         self.update_help_search_matches();
+        self.markers_dirty = true;
     }
 
-    fn update_help_search_matches(&mut self) {
-        self.help_search_highlights.clear();
-        if self.help_search_query.is_empty() {
-            self.help_search_scroll = 0;
+    /// Sends the current help lines/query/mode to the marker worker, tagging
+    /// the request with a fresh generation so a stale response that arrives
+    /// after the query has moved on again gets dropped in
+    /// `apply_marker_response` rather than overwriting newer markers.
+    fn dispatch_markers(&mut self, marker_tx: &mpsc::Sender<MarkerRequest>) {
+        self.marker_generation += 1;
+        let _ = marker_tx.send(MarkerRequest {
+            lines: self.help_lines.clone(),
+            query: self.help_search_query.clone(),
+            whole_word: self.whole_word,
+            generation: self.marker_generation,
+        });
+    }
+
+    fn apply_marker_response(&mut self, response: MarkerResponse) {
+        if response.generation != self.marker_generation {
             return;
         }
+        self.help_markers = response.line_indices;
+    }
 
-        let needle = self.help_search_query.to_lowercase();
-        let mut first_line = None;
+    /// Commits the in-progress query as the active search: from here on,
+    /// `n`/`N` (and a repeated Enter) walk `help_search_highlights` instead
+    /// of editing the query further, vim-`/`-search style.
+    fn commit_help_search(&mut self) {
+        self.help_search_committed = true;
+        self.current_match = 0;
+        self.scroll_to_current_match();
+    }
 
-        for (line_idx, line) in self.help_lines.iter().enumerate() {
-            let line_lower = line.to_lowercase();
-            if line_lower.is_empty() {
-                continue;
-            }
+    fn next_help_match(&mut self) {
+        if self.help_search_highlights.is_empty() {
+            return;
+        }
+        self.current_match = (self.current_match + 1) % self.help_search_highlights.len();
+        self.scroll_to_current_match();
+    }
 
-            let mut start_idx = 0;
-            while let Some(pos) = line_lower[start_idx..].find(&needle) {
-                let absolute = start_idx + pos;
-                let end_char = absolute + needle.len();
-                self.help_search_highlights
-                    .push((line_idx, absolute, end_char.min(line.len())));
-                if first_line.is_none() {
-                    first_line = Some(line_idx as u16);
-                }
-                start_idx = absolute + 1;
-                if start_idx >= line_lower.len() {
-                    break;
-                }
-            }
+    fn prev_help_match(&mut self) {
+        if self.help_search_highlights.is_empty() {
+            return;
+        }
+        self.current_match = if self.current_match == 0 {
+            self.help_search_highlights.len() - 1
+        } else {
+            self.current_match - 1
+        };
+        self.scroll_to_current_match();
+    }
+
+    fn scroll_to_current_match(&mut self) {
+        if let Some(&(line_idx, _, _)) = self.help_search_highlights.get(self.current_match) {
+            self.help_search_scroll = line_idx as u16;
+            self.help_search_manual_scroll = true;
+        }
+    }
+
+    /// A `"Help Matches — match i/N"` title, or the plain title when there
+    /// are no matches (empty query or nothing found).
+    fn help_search_title(&self) -> String {
+        if self.help_search_highlights.is_empty() {
+            "Help Matches".to_string()
+        } else {
+            format!(
+                "Help Matches — match {}/{}",
+                self.current_match + 1,
+                self.help_search_highlights.len()
+            )
         }
+    }
+
+    fn update_help_search_matches(&mut self) {
+        self.current_match = 0;
+        if self.help_search_query.is_empty() {
+            self.help_search_highlights.clear();
+            self.help_search_scroll = 0;
+            return;
+        }
+
+        self.help_search_highlights =
+            find_help_matches(&self.help_lines, &self.help_search_query, self.whole_word);
 
         if !self.help_search_manual_scroll {
-            if let Some(line) = first_line {
-                self.help_search_scroll = line;
-            } else {
-                self.help_search_scroll = 0;
-            }
+            self.help_search_scroll = self
+                .help_search_highlights
+                .first()
+                .map(|&(line_idx, _, _)| line_idx as u16)
+                .unwrap_or(0);
         }
     }
 
     fn help_text_widget(&self) -> Text<'static> {
-        let lines: Vec<Line> = self
-            .help_lines
-            .iter()
-            .map(|line| {
-                if line.trim_start().starts_with('$') {
-                    Line::styled(line.clone(), Style::default().fg(Color::Yellow))
-                } else {
-                    Line::raw(line.clone())
-                }
-            })
-            .collect();
-        Text::from(lines)
+        Text::from(self.styled_help_lines.clone())
     }
 
     fn help_search_text_widget(&self) -> Text<'static> {
@@ -540,25 +956,34 @@ impl HistoryUiState {
         for (idx, line) in self.help_lines.iter().enumerate() {
             let mut spans = Vec::new();
             let mut cursor = 0;
-            let mut highlights: Vec<(usize, usize)> = self
+            let mut highlights: Vec<(usize, usize, usize)> = self
                 .help_search_highlights
                 .iter()
-                .filter(|(line_idx, _, _)| *line_idx == idx)
-                .map(|(_, start, end)| (*start, *end))
+                .enumerate()
+                .filter(|(_, (line_idx, _, _))| *line_idx == idx)
+                .map(|(global_idx, &(_, start, end))| (global_idx, start, end))
                 .collect();
-            highlights.sort_by_key(|&(start, _)| start);
+            highlights.sort_by_key(|&(_, start, _)| start);
 
-            for (start, end) in highlights {
+            for (global_idx, start, end) in highlights {
                 if start > cursor {
                     spans.push(Span::raw(line[cursor..start].to_string()));
                 }
                 let highlight_end = end.min(line.len());
-                spans.push(Span::styled(
-                    line[start..highlight_end].to_string(),
+                let style = if global_idx == self.current_match {
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD)
+                } else {
                     Style::default()
                         .fg(Color::Black)
                         .bg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
+                        .add_modifier(Modifier::BOLD)
+                };
+                spans.push(Span::styled(
+                    line[start..highlight_end].to_string(),
+                    style,
                 ));
                 cursor = highlight_end;
             }
@@ -597,6 +1022,186 @@ impl HistoryUiState {
     }
 }
 
+/// Splits `command` into alternating raw/bold-yellow spans at the char
+/// positions in `match_indices`, so the results list shows *why* each
+/// command matched. Iterates by `char`s (not bytes) so multi-byte UTF-8
+/// commands never get sliced mid-codepoint.
+fn highlighted_match_spans(command: &str, match_indices: &[usize]) -> Vec<Span<'static>> {
+    if match_indices.is_empty() {
+        return vec![Span::raw(command.to_string())];
+    }
+    let matched: std::collections::HashSet<usize> = match_indices.iter().copied().collect();
+
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut current_matched = false;
+    let mut started = false;
+
+    for (idx, ch) in command.chars().enumerate() {
+        let is_match = matched.contains(&idx);
+        if started && is_match != current_matched {
+            spans.push(match_span(std::mem::take(&mut current), current_matched));
+        }
+        current.push(ch);
+        current_matched = is_match;
+        started = true;
+    }
+    if !current.is_empty() {
+        spans.push(match_span(current, current_matched));
+    }
+    spans
+}
+
+fn match_span(text: String, matched: bool) -> Span<'static> {
+    if matched {
+        Span::styled(
+            text,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        Span::raw(text)
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// zellij's word-boundary rule: a position is a boundary when the adjacent
+/// character is absent (string edge) or is neither ASCII-alphanumeric nor
+/// `_`.
+fn is_boundary(c: Option<char>) -> bool {
+    c.map(|c| !is_word_char(c)).unwrap_or(true)
+}
+
+/// Whether the match spanning `command`'s char indices `match_indices` is a
+/// whole word, i.e. the chars immediately before its first and after its
+/// last matched index are both boundaries.
+fn is_whole_word_match(command: &str, match_indices: &[usize]) -> bool {
+    if match_indices.is_empty() {
+        return true;
+    }
+    let chars: Vec<char> = command.chars().collect();
+    let start = *match_indices.iter().min().unwrap();
+    let end = *match_indices.iter().max().unwrap();
+    let before = start.checked_sub(1).and_then(|i| chars.get(i)).copied();
+    let after = chars.get(end + 1).copied();
+    is_boundary(before) && is_boundary(after)
+}
+
+/// Same rule as `is_whole_word_match`, for a `[start, end)` byte range
+/// within a help-search `line`.
+fn is_whole_word_highlight(line: &str, start: usize, end: usize) -> bool {
+    let before = line[..start].chars().next_back();
+    let after = line[end..].chars().next();
+    is_boundary(before) && is_boundary(after)
+}
+
+/// Finds every case-insensitive occurrence of `query` in `lines`, returning
+/// `(line_idx, start_byte, end_byte)` triples, honoring `whole_word`. Shared
+/// by `update_help_search_matches` (popup highlighting) and the marker
+/// worker (scrollbar density), so the two never drift apart on what counts
+/// as a match.
+fn find_help_matches(lines: &[String], query: &str, whole_word: bool) -> Vec<(usize, usize, usize)> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let needle = query.to_lowercase();
+    let mut highlights = Vec::new();
+
+    for (line_idx, line) in lines.iter().enumerate() {
+        let line_lower = line.to_lowercase();
+        if line_lower.is_empty() {
+            continue;
+        }
+
+        let mut start_idx = 0;
+        while let Some(pos) = line_lower[start_idx..].find(&needle) {
+            let absolute = start_idx + pos;
+            let end = (absolute + needle.len()).min(line.len());
+            if !whole_word || is_whole_word_highlight(line, absolute, end) {
+                highlights.push((line_idx, absolute, end));
+            }
+            start_idx = absolute + 1;
+            if start_idx >= line_lower.len() {
+                break;
+            }
+        }
+    }
+
+    highlights
+}
+
+/// A request to the marker worker: recompute which `lines` contain a match
+/// for `query` so the Help pane's scrollbar gutter can show density ticks
+/// without stalling the draw loop on a large help page.
+struct MarkerRequest {
+    lines: Vec<String>,
+    query: String,
+    whole_word: bool,
+    generation: u64,
+}
+
+/// The worker's reply: the (deduped, ascending) line indices that matched,
+/// tagged with the generation of the request that produced them so a
+/// response that arrives after the query has already moved on can be
+/// dropped instead of overwriting newer markers.
+struct MarkerResponse {
+    line_indices: Vec<usize>,
+    generation: u64,
+}
+
+/// Draws a colored tick in the Help pane's scrollbar gutter for each
+/// distinct line in `markers`, bucketed onto `area`'s rows so adjacent
+/// matches that land on the same row collapse into a single tick instead of
+/// drawing a solid bar.
+fn render_help_markers(f: &mut ratatui::Frame, area: Rect, markers: &[usize], total_lines: usize) {
+    if markers.is_empty() || total_lines == 0 || area.width < 2 || area.height < 3 {
+        return;
+    }
+
+    let gutter_x = area.x + area.width.saturating_sub(2);
+    let inner_height = area.height.saturating_sub(2) as usize;
+    if inner_height == 0 {
+        return;
+    }
+
+    let mut rows: std::collections::HashSet<usize> = std::collections::HashSet::new();
+    for &line_idx in markers {
+        let row = (line_idx * inner_height) / total_lines;
+        rows.insert(row.min(inner_height - 1));
+    }
+
+    for row in rows {
+        let cell = Rect {
+            x: gutter_x,
+            y: area.y + 1 + row as u16,
+            width: 1,
+            height: 1,
+        };
+        f.render_widget(
+            Paragraph::new("▐").style(Style::default().fg(Color::Magenta)),
+            cell,
+        );
+    }
+}
+
+/// Approximates how many rendered rows `lines` occupy once word-wrapped to
+/// `width` columns, matching `Paragraph`'s `Wrap { trim: true }` closely
+/// enough to keep scroll bounds/the scrollbar thumb in the right ballpark.
+fn wrapped_line_count(lines: &[String], width: u16) -> usize {
+    let width = width.max(1) as usize;
+    lines
+        .iter()
+        .map(|line| {
+            let len = line.chars().count();
+            if len == 0 { 1 } else { len.div_ceil(width) }
+        })
+        .sum()
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)